@@ -0,0 +1,65 @@
+use std::{fs, io::Read, path::Path};
+
+use casper_event_standard::{casper_types::bytesrepr, Schemas};
+
+/// Reads an input argument.
+///
+/// An existing file path is read, `-` or no argument reads stdin,
+/// any other argument is the input itself.
+pub fn read(arg: Option<&str>) -> Result<String, String> {
+    match arg {
+        None | Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|error| format!("Can't read stdin: {}", error))?;
+            Ok(input)
+        }
+        Some(arg) if Path::new(arg).is_file() => read_file(arg),
+        Some(arg) => Ok(String::from(arg)),
+    }
+}
+
+/// Reads a file, a missing file is an error.
+pub fn read_file(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Can't read {}: {}", path, error))
+}
+
+/// Parses hex with an optional `0x` prefix.
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, String> {
+    let input = input.trim();
+    let input = input.strip_prefix("0x").unwrap_or(input);
+    input
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => Some((hex_digit(*high)? << 4) | hex_digit(*low)?),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| String::from("Invalid hex"))
+}
+
+/// Formats bytes as lowercase hex.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parses [`Schemas`] from serde JSON or `bytesrepr` hex.
+pub fn parse_schemas(input: &str) -> Result<Schemas, String> {
+    if input.trim_start().starts_with('{') {
+        parse_json_schemas(input)
+    } else {
+        bytesrepr::deserialize(parse_hex(input)?)
+            .map_err(|error| format!("Invalid schemas: {}", error))
+    }
+}
+
+/// Parses [`Schemas`] from serde JSON.
+pub fn parse_json_schemas(input: &str) -> Result<Schemas, String> {
+    serde_json::from_str(input).map_err(|error| format!("Invalid JSON schemas: {}", error))
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
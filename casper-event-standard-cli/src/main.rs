@@ -0,0 +1,160 @@
+//! Command-line tool to inspect and decode Casper Event Standard data.
+use std::{env, process};
+
+use casper_event_standard::{
+    casper_types::bytesrepr::ToBytes, decode_event, event_to_json, schemas_to_typescript,
+    try_full_name_from_bytes, value_to_json, Schemas,
+};
+use serde_json::Value as JsonValue;
+
+use crate::table::Table;
+
+mod input;
+mod table;
+
+const USAGE: &str = "\
+Usage: ces <COMMAND> [--json]
+
+Commands:
+  decode-event --schemas <FILE> <HEX>  Decodes an event using the schemas
+  show-schemas <HEX|JSON>              Lists the events and their fields
+  name <HEX>                           Prints the full name of an event
+  encode-schemas <JSON>                Encodes serde JSON schemas as bytesrepr hex
+  typescript <HEX|JSON>                Generates TypeScript interfaces and decoders
+
+Options:
+  --json  Prints JSON instead of a table, except for typescript
+
+The --schemas file must exist. Other arguments that are existing file paths
+are read from the files, `-` or a missing argument is read from stdin.
+Schemas are bytesrepr hex, as stored under `__events_schema`, or serde JSON.";
+
+/// The parsed command-line arguments.
+#[derive(Debug, Default)]
+struct Args {
+    command: Option<String>,
+    input: Option<String>,
+    schemas: Option<String>,
+    json: bool,
+    help: bool,
+}
+
+fn main() {
+    match parse_args(env::args().skip(1)).and_then(run) {
+        Ok(output) => println!("{}", output),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut iter: I) -> Result<Args, String> {
+    let mut args = Args::default();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--json" => args.json = true,
+            "-h" | "--help" => args.help = true,
+            "--schemas" => {
+                let file = iter.next().ok_or("Missing value of --schemas")?;
+                args.schemas = Some(file);
+            }
+            _ if args.command.is_none() => args.command = Some(arg),
+            _ if args.input.is_none() => args.input = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}\n\n{}", arg, USAGE)),
+        }
+    }
+    Ok(args)
+}
+
+fn run(args: Args) -> Result<String, String> {
+    if args.help {
+        return Ok(String::from(USAGE));
+    }
+    match args.command.as_deref() {
+        Some("decode-event") => run_decode_event(&args),
+        Some("show-schemas") => run_show_schemas(&args),
+        Some("name") => run_name(&args),
+        Some("encode-schemas") => run_encode_schemas(&args),
+        Some("typescript") => run_typescript(&args),
+        Some(command) => Err(format!("Unknown command: {}\n\n{}", command, USAGE)),
+        None => Err(String::from(USAGE)),
+    }
+}
+
+fn run_decode_event(args: &Args) -> Result<String, String> {
+    let schemas_file = args
+        .schemas
+        .as_deref()
+        .ok_or("decode-event requires --schemas <FILE>")?;
+    let schemas = input::parse_schemas(&input::read_file(schemas_file)?)?;
+    let bytes = input::parse_hex(&input::read(args.input.as_deref())?)?;
+    let event = decode_event(&schemas, &bytes).map_err(|error| error.to_string())?;
+    let schema = &schemas.0[event.name()];
+
+    if args.json {
+        return to_pretty_json(&event_to_json(schema, &event));
+    }
+    let mut table = Table::new(&["FIELD", "TYPE", "VALUE"]);
+    for ((name, ty), (_, value)) in schema.elems().iter().zip(event.fields()) {
+        let value = match value_to_json(&ty.0, value) {
+            JsonValue::String(value) => value,
+            value => value.to_string(),
+        };
+        table.add_row(vec![name.clone(), ty.to_string(), value]);
+    }
+    Ok(format!("Event: {}\n\n{}", event.name(), table.render()))
+}
+
+fn run_show_schemas(args: &Args) -> Result<String, String> {
+    let schemas = input::parse_schemas(&input::read(args.input.as_deref())?)?;
+    if args.json {
+        return serde_json::to_string_pretty(&schemas).map_err(|error| error.to_string());
+    }
+    Ok(schemas_table(&schemas).render())
+}
+
+fn run_name(args: &Args) -> Result<String, String> {
+    let bytes = input::parse_hex(&input::read(args.input.as_deref())?)?;
+    let name = try_full_name_from_bytes(&bytes).map_err(|error| error.to_string())?;
+    if args.json {
+        return to_pretty_json(&JsonValue::String(name));
+    }
+    Ok(name)
+}
+
+fn run_encode_schemas(args: &Args) -> Result<String, String> {
+    let schemas = input::parse_json_schemas(&input::read(args.input.as_deref())?)?;
+    let bytes = schemas.to_bytes().map_err(|error| error.to_string())?;
+    let hex = input::to_hex(&bytes);
+    if args.json {
+        return to_pretty_json(&JsonValue::String(hex));
+    }
+    Ok(hex)
+}
+
+fn run_typescript(args: &Args) -> Result<String, String> {
+    if args.json {
+        return Err(format!("typescript doesn't support --json\n\n{}", USAGE));
+    }
+    let schemas = input::parse_schemas(&input::read(args.input.as_deref())?)?;
+    Ok(String::from(schemas_to_typescript(&schemas).trim_end()))
+}
+
+/// Lists every field of every event, an event without fields takes a single row.
+fn schemas_table(schemas: &Schemas) -> Table {
+    let mut table = Table::new(&["EVENT", "FIELD", "TYPE"]);
+    for (event, schema) in &schemas.0 {
+        if schema.elems().is_empty() {
+            table.add_row(vec![event.clone(), String::new(), String::new()]);
+        }
+        for (name, ty) in schema.elems() {
+            table.add_row(vec![event.clone(), name.clone(), ty.to_string()]);
+        }
+    }
+    table
+}
+
+fn to_pretty_json(value: &JsonValue) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|error| error.to_string())
+}
@@ -0,0 +1,49 @@
+/// A plain text table with columns aligned to the widest cell.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Table {
+            header: header.iter().map(|cell| cell.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn render(&self) -> String {
+        let widths = self
+            .header
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(index))
+                    .map(|cell| cell.chars().count())
+                    .fold(cell.chars().count(), usize::max)
+            })
+            .collect::<Vec<_>>();
+        let separator = widths.iter().map(|width| "-".repeat(*width)).collect();
+        let lines = [self.header.clone(), separator]
+            .into_iter()
+            .chain(self.rows.iter().cloned())
+            .map(|row| render_row(&row, &widths))
+            .collect::<Vec<_>>();
+        lines.join("\n")
+    }
+}
+
+fn render_row(row: &[String], widths: &[usize]) -> String {
+    let cells = row
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:width$}", cell, width = width))
+        .collect::<Vec<_>>();
+    cells.join("  ").trim_end().to_string()
+}
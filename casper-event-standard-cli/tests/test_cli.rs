@@ -0,0 +1,211 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use casper_event_standard::{Event, Schemas};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, Key, U256};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    memo: Option<String>,
+}
+
+#[derive(Event)]
+struct Pause {}
+
+fn schemas() -> Schemas {
+    Schemas::new().with::<Transfer>().with::<Pause>()
+}
+
+fn transfer_hex() -> String {
+    let transfer = Transfer {
+        amount: U256::from(1_000),
+        from: Key::Account(AccountHash::new([1u8; 32])),
+        memo: None,
+    };
+    hex(&transfer.to_bytes().unwrap())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn ces(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ces"))
+        .args(args)
+        .stdin(match stdin {
+            Some(_) => Stdio::piped(),
+            None => Stdio::null(),
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    if let Some(stdin) = stdin {
+        let mut pipe = child.stdin.take().unwrap();
+        pipe.write_all(stdin.as_bytes()).unwrap();
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn write_file(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("ces-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_name() {
+    let output = stdout(ces(&["name", &transfer_hex()], None));
+    assert_eq!(output, "event_Transfer\n");
+
+    let output = stdout(ces(&["name", "--json", "-"], Some(&transfer_hex())));
+    assert_eq!(output, "\"event_Transfer\"\n");
+}
+
+#[test]
+fn test_decode_event_table() {
+    let schemas = write_file("decode-table", &hex(&schemas().to_bytes().unwrap()));
+    let output = stdout(ces(
+        &[
+            "decode-event",
+            "--schemas",
+            schemas.to_str().unwrap(),
+            &transfer_hex(),
+        ],
+        None,
+    ));
+    let account = format!("account-hash-{}", "01".repeat(32));
+    let expected = [
+        String::from("Event: Transfer"),
+        String::new(),
+        String::from("FIELD   TYPE            VALUE"),
+        format!("------  --------------  {}", "-".repeat(account.len())),
+        String::from("amount  U256            1000"),
+        format!("from    Key             {}", account),
+        String::from("memo    Option<String>  null"),
+        String::new(),
+    ];
+    assert_eq!(output, expected.join("\n"));
+}
+
+#[test]
+fn test_decode_event_json() {
+    let json_schemas = serde_json::to_string(&schemas()).unwrap();
+    let schemas = write_file("decode-json", &json_schemas);
+    let output = stdout(ces(
+        &[
+            "decode-event",
+            "--json",
+            "--schemas",
+            schemas.to_str().unwrap(),
+        ],
+        Some(&transfer_hex()),
+    ));
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "name": "Transfer",
+            "fields": {
+                "amount": "1000",
+                "from": format!("account-hash-{}", "01".repeat(32)),
+                "memo": null,
+            }
+        })
+    );
+}
+
+#[test]
+fn test_decode_event_without_schemas() {
+    let output = ces(&["decode-event", &transfer_hex()], None);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "decode-event requires --schemas <FILE>\n"
+    );
+}
+
+#[test]
+fn test_decode_event_missing_schemas_file() {
+    let output = ces(
+        &["decode-event", "--schemas", "missing.hex", &transfer_hex()],
+        None,
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Can't read missing.hex: "));
+}
+
+#[test]
+fn test_show_schemas() {
+    let bytes = hex(&schemas().to_bytes().unwrap());
+    let output = stdout(ces(&["show-schemas", &bytes], None));
+    assert_eq!(
+        output,
+        [
+            "EVENT     FIELD   TYPE",
+            "--------  ------  --------------",
+            "Pause",
+            "Transfer  amount  U256",
+            "Transfer  from    Key",
+            "Transfer  memo    Option<String>",
+            "",
+        ]
+        .join("\n")
+    );
+
+    let output = stdout(ces(&["show-schemas", "--json", &bytes], None));
+    let parsed: Schemas = serde_json::from_str(&output).unwrap();
+    assert_eq!(parsed, schemas());
+}
+
+#[test]
+fn test_encode_schemas() {
+    let json = serde_json::to_string(&schemas()).unwrap();
+    let output = stdout(ces(&["encode-schemas"], Some(&json)));
+    assert_eq!(output, format!("{}\n", hex(&schemas().to_bytes().unwrap())));
+}
+
+#[test]
+fn test_typescript() {
+    let json = serde_json::to_string(&schemas()).unwrap();
+    let output = stdout(ces(&["typescript", "-"], Some(&json)));
+    assert!(output.contains("\nexport interface Transfer {"));
+    assert!(output.contains("\nexport function decodePause(bytes: Uint8Array): Pause {"));
+    assert!(output.contains("\nexport function decodeEvent(bytes: Uint8Array): Event {"));
+
+    let output = ces(&["typescript", "--json", "-"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("typescript doesn't support --json\n"));
+}
+
+#[test]
+fn test_invalid_input() {
+    let output = ces(&["name", "0xzz"], None);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "Invalid hex\n");
+
+    let output = ces(&["unknown"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Unknown command: unknown\n"));
+}
@@ -0,0 +1,107 @@
+//! Parsing of `#[event(...)]` attributes.
+
+use proc_macro2::Ident;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, LitStr, Token, Type,
+};
+
+const ATTR_NAME: &str = "event";
+
+/// Options of a single field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// `#[event(rename = "name")]` - the name of the schema element.
+    pub rename: Option<LitStr>,
+    /// `#[event(skip)]` - the field is not serialized and is `Default` when deserialized.
+    pub skip: bool,
+    /// `#[event(cl_type = Type)]` - the type providing the `CLType` of the schema element.
+    pub cl_type: Option<Type>,
+}
+
+/// Options of a struct or an enum variant.
+#[derive(Default)]
+pub(crate) struct EventAttrs {
+    /// `#[event(name = "name")]` - the name of the event.
+    pub name: Option<LitStr>,
+}
+
+struct EventAttr(LitStr);
+
+impl Parse for EventAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident != "name" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Expected `name`. The `event_` prefix is fixed by the standard.",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let name: LitStr = input.parse()?;
+        if name.value().is_empty() {
+            return Err(syn::Error::new(name.span(), "Event name can't be empty."));
+        }
+        Ok(EventAttr(name))
+    }
+}
+
+enum FieldAttr {
+    Rename(LitStr),
+    Skip,
+    ClType(Type),
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "rename" => {
+                input.parse::<Token![=]>()?;
+                Ok(FieldAttr::Rename(input.parse()?))
+            }
+            "skip" => Ok(FieldAttr::Skip),
+            "cl_type" => {
+                input.parse::<Token![=]>()?;
+                Ok(FieldAttr::ClType(input.parse()?))
+            }
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "Expected `rename`, `skip` or `cl_type`.",
+            )),
+        }
+    }
+}
+
+pub(crate) fn parse_event_attrs(attrs: &[Attribute]) -> Result<EventAttrs, syn::Error> {
+    let mut result = EventAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_NAME)) {
+        let parsed = attr.parse_args_with(Punctuated::<EventAttr, Token![,]>::parse_terminated)?;
+        for EventAttr(name) in parsed {
+            result.name = Some(name);
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs, syn::Error> {
+    let mut result = FieldAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_NAME)) {
+        let parsed = attr.parse_args_with(Punctuated::<FieldAttr, Token![,]>::parse_terminated)?;
+        for field_attr in parsed {
+            match field_attr {
+                FieldAttr::Rename(name) => result.rename = Some(name),
+                FieldAttr::Skip => result.skip = true,
+                FieldAttr::ClType(ty) => result.cl_type = Some(ty),
+            }
+        }
+        if result.skip && (result.rename.is_some() || result.cl_type.is_some()) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Skipped field can't be renamed or have a `cl_type`.",
+            ));
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,208 @@
+//! `EventField` macro.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::{parse_quote, DeriveInput, Generics, Type};
+
+use crate::{
+    add_trait_bounds, attrs, extract_fields, generate_construct_fields,
+    generate_deserialize_fields, serialized_fields, Field,
+};
+
+pub(crate) fn derive_event_field_code(input: TokenStream2) -> Result<TokenStream2, syn::Error> {
+    let input: DeriveInput = syn::parse2(input)?;
+    if let Some(name) = attrs::parse_event_attrs(&input.attrs)?.name {
+        return Err(syn::Error::new(
+            name.span(),
+            "`name` is not supported in `EventField`, it is not an event.",
+        ));
+    }
+    let field_ident = input.ident.clone();
+    let generics = add_trait_bounds(input.generics.clone());
+    let fields = extract_fields(input)?;
+    // The struct is a tuple, the names of its fields are not recorded anywhere.
+    if let Some(name) = fields.iter().find_map(|field| field.attrs.rename.as_ref()) {
+        return Err(syn::Error::new(
+            name.span(),
+            "`rename` is not supported in `EventField`, the fields are represented as a tuple.",
+        ));
+    }
+
+    let cl_typed_impl = generate_cl_typed_impl(&field_ident, &generics, &fields);
+    let has_any_impl = generate_has_any_impl(&field_ident, &generics, &fields);
+    let to_bytes_impl = generate_to_bytes_impl(&field_ident, &generics, &fields);
+    let from_bytes_impl = generate_from_bytes_impl(&field_ident, &generics, &fields);
+
+    Ok(quote! {
+        #cl_typed_impl
+        #has_any_impl
+        #to_bytes_impl
+        #from_bytes_impl
+    })
+}
+
+fn generate_cl_typed_impl(
+    field_ident: &Ident,
+    generics: &Generics,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let types = serialized_fields(fields)
+        .map(|field| field.schema_type())
+        .collect::<Vec<_>>();
+    let cl_type = generate_tuple_cl_type(&types);
+
+    quote! {
+        impl #impl_generics casper_event_standard::casper_types::CLTyped for #field_ident #ty_generics #where_clause {
+            fn cl_type() -> casper_event_standard::casper_types::CLType {
+                #cl_type
+            }
+        }
+    }
+}
+
+/// Derives `HasAny` from the fields whose types use the type parameters.
+///
+/// Other field types are concrete, so they are checked when the struct is serialized.
+/// A generic field makes `HasAny` conditional on its type, e.g. `Meta<T>` has it
+/// if `T: HasAny` and contains `CLType::Any` if `T` does.
+fn generate_has_any_impl(
+    field_ident: &Ident,
+    generics: &Generics,
+    fields: &[Field],
+) -> TokenStream2 {
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let generic_types = serialized_fields(fields)
+        .map(|field| field.schema_type())
+        .filter(|ty| uses_type_params(ty.to_token_stream(), &params))
+        .collect::<Vec<_>>();
+
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in &generic_types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: casper_event_standard::HasAny));
+    }
+    let any = generic_types
+        .iter()
+        .map(|ty| quote!(<#ty as casper_event_standard::HasAny>::Any))
+        .reduce(|acc, any| quote!(<#acc as casper_event_standard::AnyFlag>::Or<#any>))
+        .unwrap_or_else(|| quote!(casper_event_standard::NoAny));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics casper_event_standard::HasAny for #field_ident #ty_generics #where_clause {
+            type Any = #any;
+        }
+    }
+}
+
+/// Whether the type refers to any of the type parameters.
+fn uses_type_params(tokens: TokenStream2, params: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.contains(&ident),
+        TokenTree::Group(group) => uses_type_params(group.stream(), params),
+        _ => false,
+    })
+}
+
+/// Builds the `CLType` of a tuple of `types`.
+///
+/// More than three types are represented as nested tuples, e.g. `(a, b, (c, d))`,
+/// which serialize to the same bytes as a flat tuple.
+fn generate_tuple_cl_type(types: &[&Type]) -> TokenStream2 {
+    let boxed = |ty: &Type| {
+        quote! {
+            casper_event_standard::alloc::boxed::Box::new(<#ty as casper_event_standard::casper_types::CLTyped>::cl_type())
+        }
+    };
+    match types {
+        [] => quote!(casper_event_standard::casper_types::CLType::Unit),
+        [t1] => {
+            let t1 = boxed(t1);
+            quote!(casper_event_standard::casper_types::CLType::Tuple1([#t1]))
+        }
+        [t1, t2] => {
+            let (t1, t2) = (boxed(t1), boxed(t2));
+            quote!(casper_event_standard::casper_types::CLType::Tuple2([#t1, #t2]))
+        }
+        [t1, t2, t3] => {
+            let (t1, t2, t3) = (boxed(t1), boxed(t2), boxed(t3));
+            quote!(casper_event_standard::casper_types::CLType::Tuple3([#t1, #t2, #t3]))
+        }
+        [t1, t2, rest @ ..] => {
+            let (t1, t2) = (boxed(t1), boxed(t2));
+            let rest = generate_tuple_cl_type(rest);
+            quote! {
+                casper_event_standard::casper_types::CLType::Tuple3([#t1, #t2, casper_event_standard::alloc::boxed::Box::new(#rest)])
+            }
+        }
+    }
+}
+
+fn generate_to_bytes_impl(
+    field_ident: &Ident,
+    generics: &Generics,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let append_bytes = serialized_fields(fields)
+        .flat_map(|field| {
+            let member = &field.member;
+            let validate = field.validate(quote!(&self.#member));
+            quote! {
+                #validate
+                vec.extend(self.#member.to_bytes()?);
+            }
+        })
+        .collect::<TokenStream2>();
+    let mut sum_serialized_lengths = quote! {
+        let mut size = 0;
+    };
+    sum_serialized_lengths.append_all(
+        serialized_fields(fields)
+            .map(|field| &field.member)
+            .map(|member| quote!(size += self.#member.serialized_length();)),
+    );
+
+    quote! {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::ToBytes for #field_ident #ty_generics #where_clause {
+            fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                #append_bytes
+                Ok(vec)
+            }
+
+            fn serialized_length(&self) -> usize {
+                #sum_serialized_lengths
+                size
+            }
+        }
+    }
+}
+
+fn generate_from_bytes_impl(
+    field_ident: &Ident,
+    generics: &Generics,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let deserialize_fields = generate_deserialize_fields(fields);
+    let construct_struct = generate_construct_fields(fields);
+
+    quote! {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::FromBytes for #field_ident #ty_generics #where_clause {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                #deserialize_fields
+                let value = #field_ident {
+                    #construct_struct
+                };
+                Ok((value, bytes))
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! `EventSet` macro.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Data, DataEnum, DeriveInput, Fields, Generics, Type};
+
+use crate::add_trait_bounds;
+
+pub(crate) fn derive_event_set_code(input: TokenStream2) -> Result<TokenStream2, syn::Error> {
+    let input: DeriveInput = syn::parse2(input)?;
+    let set_ident = input.ident.clone();
+    let generics = add_trait_bounds(input.generics.clone());
+    let variants = extract_variants(input)?;
+
+    let event_instances_impl = generate_event_instances_impl(&set_ident, &generics, &variants);
+    let set_impl = generate_set_impl(&set_ident, &generics, &variants);
+
+    Ok(quote! {
+        #event_instances_impl
+        #set_impl
+    })
+}
+
+fn generate_event_instances_impl(
+    set_ident: &Ident,
+    generics: &Generics,
+    variants: &[(Ident, Type)],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let extend_schemas = variants
+        .iter()
+        .map(|(_, ty)| {
+            quote! {
+                schemas.extend(<#ty as casper_event_standard::EventInstances>::schemas());
+            }
+        })
+        .collect::<TokenStream2>();
+    let extend_names = variants
+        .iter()
+        .map(|(_, ty)| {
+            quote! {
+                names.extend(<#ty as casper_event_standard::EventInstances>::names());
+            }
+        })
+        .collect::<TokenStream2>();
+
+    quote! {
+        impl #impl_generics casper_event_standard::EventInstances for #set_ident #ty_generics #where_clause {
+            fn schemas() -> casper_event_standard::alloc::vec::Vec<(casper_event_standard::alloc::string::String, casper_event_standard::Schema)> {
+                let mut schemas = casper_event_standard::alloc::vec::Vec::new();
+                #extend_schemas
+                schemas
+            }
+
+            fn names() -> casper_event_standard::alloc::vec::Vec<casper_event_standard::alloc::string::String> {
+                let mut names = casper_event_standard::alloc::vec::Vec::new();
+                #extend_names
+                names
+            }
+        }
+    }
+}
+
+fn generate_set_impl(
+    set_ident: &Ident,
+    generics: &Generics,
+    variants: &[(Ident, Type)],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let try_variants = variants
+        .iter()
+        .map(|(variant_ident, ty)| {
+            quote! {
+                if <#ty as casper_event_standard::EventInstances>::names().iter().any(|expected| expected == name) {
+                    return <#ty as casper_event_standard::casper_types::bytesrepr::FromBytes>::from_bytes(bytes)
+                        .map(|(event, bytes)| (#set_ident::#variant_ident(event), bytes))
+                        .map_err(|_| casper_event_standard::Error::InvalidEvent);
+                }
+            }
+        })
+        .collect::<TokenStream2>();
+
+    quote! {
+        impl #impl_generics #set_ident #ty_generics #where_clause {
+            /// Deserializes an event of any of the variants, based on its name.
+            pub fn from_event_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::Error> {
+                let name = casper_event_standard::event_name_from_bytes(bytes)?;
+                #try_variants
+                Err(casper_event_standard::Error::UnexpectedEvent)
+            }
+
+            /// Builds [`Schemas`](casper_event_standard::Schemas) of all the variants.
+            pub fn all_schemas() -> casper_event_standard::Schemas {
+                casper_event_standard::Schemas::new().with::<Self>()
+            }
+        }
+    }
+}
+
+fn extract_variants(input: DeriveInput) -> Result<Vec<(Ident, Type)>, syn::Error> {
+    let variants = match input.data {
+        Data::Enum(DataEnum { variants, .. }) if !variants.is_empty() => variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Expected an enum with at least one variant.",
+            ))
+        }
+    };
+    variants
+        .into_iter()
+        .map(|variant| match variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field = fields.unnamed.into_iter().next().unwrap();
+                Ok((variant.ident, field.ty))
+            }
+            _ => Err(syn::Error::new_spanned(
+                variant,
+                "Expected an enum variant with a single unnamed field.",
+            )),
+        })
+        .collect()
+}
@@ -1,13 +1,152 @@
-//! `Event` macro for `casper-event-standard` crate.
+//! `Event`, `EventField` and `EventSet` macros for `casper-event-standard` crate.
 
+mod attrs;
+mod event_field;
+mod event_set;
+
+use attrs::{EventAttrs, FieldAttrs};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::{format_ident, quote, TokenStreamExt};
-use syn::{Data, DataStruct, DeriveInput, Fields, Type};
+use quote::{format_ident, quote, quote_spanned, TokenStreamExt};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DataStruct, DeriveInput, Fields,
+    GenericParam, Generics, LitStr, Member, Type,
+};
 
 const EVENT_PREFIX: &str = "event_";
 
-#[proc_macro_derive(Event)]
+/// The name of an event, the ident of a struct or an enum variant unless overridden.
+struct EventName {
+    ident: Ident,
+    attrs: EventAttrs,
+}
+
+impl EventName {
+    fn from_syn(ident: Ident, attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let attrs = attrs::parse_event_attrs(attrs)?;
+        Ok(EventName { ident, attrs })
+    }
+
+    /// The name under which the schema is registered.
+    fn name(&self) -> TokenStream2 {
+        match &self.attrs.name {
+            Some(name) => quote!(#name),
+            None => {
+                let ident = &self.ident;
+                quote!(stringify!(#ident))
+            }
+        }
+    }
+
+    /// The serialized name, including the prefix.
+    fn full_name(&self) -> TokenStream2 {
+        match &self.attrs.name {
+            Some(name) => {
+                let full_name =
+                    LitStr::new(&format!("{EVENT_PREFIX}{}", name.value()), name.span());
+                quote!(#full_name)
+            }
+            None => {
+                let full_name = format_ident!("{EVENT_PREFIX}{}", self.ident);
+                quote!(stringify!(#full_name))
+            }
+        }
+    }
+
+    fn value(&self) -> String {
+        match &self.attrs.name {
+            Some(name) => name.value(),
+            None => self.ident.to_string(),
+        }
+    }
+}
+
+/// A variant of an enum event.
+struct Variant {
+    ident: Ident,
+    name: EventName,
+    fields: Vec<Field>,
+}
+
+/// A named or unnamed field of an event.
+struct Field {
+    /// The field name or index.
+    member: Member,
+    /// The variable the field is bound to.
+    ident: Ident,
+    ty: Type,
+    attrs: FieldAttrs,
+}
+
+impl Field {
+    fn from_syn(index: usize, field: syn::Field) -> Result<Self, syn::Error> {
+        let attrs = attrs::parse_field_attrs(&field.attrs)?;
+        let (member, ident) = match field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident),
+            None => (
+                Member::Unnamed(index.into()),
+                format_ident!("field_{index}"),
+            ),
+        };
+        Ok(Field {
+            member,
+            ident,
+            ty: field.ty,
+            attrs,
+        })
+    }
+
+    /// The name of the element in the schema.
+    fn schema_name(&self) -> TokenStream2 {
+        match &self.attrs.rename {
+            Some(name) => quote!(#name),
+            None => {
+                let member = &self.member;
+                quote!(stringify!(#member))
+            }
+        }
+    }
+
+    /// The name of the element in the schema as a string, to compare it with other elements.
+    fn schema_name_value(&self) -> String {
+        match (&self.attrs.rename, &self.member) {
+            (Some(name), _) => name.value(),
+            (None, Member::Named(ident)) => ident.to_string(),
+            (None, Member::Unnamed(index)) => index.index.to_string(),
+        }
+    }
+
+    /// The type that provides the `CLType` of the element in the schema.
+    fn schema_type(&self) -> &Type {
+        self.attrs.cl_type.as_ref().unwrap_or(&self.ty)
+    }
+
+    /// Validates the type of the field, given a reference to its value.
+    ///
+    /// Types implementing `HasAny` are checked at compile time,
+    /// others only at runtime.
+    fn validate(&self, value_ref: TokenStream2) -> TokenStream2 {
+        let ty = self.schema_type();
+        let runtime_check = match &self.attrs.cl_type {
+            Some(cl_type) => quote! {
+                casper_event_standard::validate_cl_type(&<#cl_type as casper_event_standard::casper_types::CLTyped>::cl_type())?;
+            },
+            None => quote! {
+                casper_event_standard::validate_type(#value_ref)?;
+            },
+        };
+        // The type error of a nested event points at the field type.
+        let static_check = quote_spanned! {ty.span()=>
+            casper_event_standard::assert_no_any!(#ty);
+        };
+        quote! {
+            #static_check
+            #runtime_check
+        }
+    }
+}
+
+#[proc_macro_derive(Event, attributes(event))]
 pub fn derive_event(input: TokenStream) -> TokenStream {
     match derive_event_code(input.into()) {
         Ok(output) => output,
@@ -16,15 +155,43 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro_derive(EventField, attributes(event))]
+pub fn derive_event_field(input: TokenStream) -> TokenStream {
+    match event_field::derive_event_field_code(input.into()) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+    .into()
+}
+
+#[proc_macro_derive(EventSet)]
+pub fn derive_event_set(input: TokenStream) -> TokenStream {
+    match event_set::derive_event_set_code(input.into()) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    }
+    .into()
+}
+
 fn derive_event_code(input: TokenStream2) -> Result<TokenStream2, syn::Error> {
     let input: DeriveInput = syn::parse2(input)?;
+    match input.data {
+        Data::Enum(_) => derive_enum_event_code(input),
+        _ => derive_struct_event_code(input),
+    }
+}
+
+fn derive_struct_event_code(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
     let event_ident = input.ident.clone();
+    let generics = add_trait_bounds(input.generics.clone());
+    let event_name = EventName::from_syn(input.ident.clone(), &input.attrs)?;
     let fields = extract_fields(input)?;
 
-    let cl_typed_impl = generate_cl_typed_impl(&event_ident);
-    let to_bytes_impl = generate_to_bytes_impl(&event_ident, &fields);
-    let from_bytes_impl = generate_from_bytes_impl(&event_ident, &fields);
-    let event_instance_impl = generate_event_instance_impl(&event_ident, &fields);
+    let cl_typed_impl = generate_cl_typed_impl(&event_ident, &generics);
+    let to_bytes_impl = generate_to_bytes_impl(&event_ident, &generics, &event_name, &fields);
+    let from_bytes_impl = generate_from_bytes_impl(&event_ident, &generics, &event_name, &fields);
+    let event_instance_impl =
+        generate_event_instance_impl(&event_ident, &generics, &event_name, &fields);
 
     Ok(quote! {
         #cl_typed_impl
@@ -34,26 +201,72 @@ fn derive_event_code(input: TokenStream2) -> Result<TokenStream2, syn::Error> {
     })
 }
 
-fn generate_cl_typed_impl(event_ident: &Ident) -> TokenStream2 {
+fn derive_enum_event_code(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let event_ident = input.ident.clone();
+    let generics = add_trait_bounds(input.generics.clone());
+    let variants = extract_variants(input)?;
+
+    let cl_typed_impl = generate_cl_typed_impl(&event_ident, &generics);
+    let to_bytes_impl = generate_enum_to_bytes_impl(&event_ident, &generics, &variants);
+    let from_bytes_impl = generate_enum_from_bytes_impl(&event_ident, &generics, &variants);
+    let event_instances_impl = generate_event_instances_impl(&event_ident, &generics, &variants);
+
+    Ok(quote! {
+        #cl_typed_impl
+        #to_bytes_impl
+        #from_bytes_impl
+        #event_instances_impl
+    })
+}
+
+/// Requires every type parameter to be serializable with a known `CLType`.
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param
+                .bounds
+                .push(parse_quote!(casper_event_standard::casper_types::CLTyped));
+            param.bounds.push(parse_quote!(
+                casper_event_standard::casper_types::bytesrepr::ToBytes
+            ));
+            param.bounds.push(parse_quote!(
+                casper_event_standard::casper_types::bytesrepr::FromBytes
+            ));
+        }
+    }
+    generics
+}
+
+fn generate_cl_typed_impl(event_ident: &Ident, generics: &Generics) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl casper_event_standard::casper_types::CLTyped for #event_ident {
+        impl #impl_generics casper_event_standard::casper_types::CLTyped for #event_ident #ty_generics #where_clause {
             fn cl_type() -> casper_event_standard::casper_types::CLType {
                 casper_event_standard::casper_types::CLType::Any
             }
         }
+
+        impl #impl_generics casper_event_standard::HasAny for #event_ident #ty_generics #where_clause {
+            type Any = casper_event_standard::ContainsAny;
+        }
     }
 }
 
-fn generate_to_bytes_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> TokenStream2 {
-    let name_literal = format_ident!("{EVENT_PREFIX}{event_ident}");
-    let name_literal = quote! { stringify!(#name_literal) };
-    let append_bytes = fields
-        .iter()
-        .map(|(ident, _)| ident)
-        .flat_map(|ident| {
+fn generate_to_bytes_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    event_name: &EventName,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name_literal = event_name.full_name();
+    let append_bytes = serialized_fields(fields)
+        .flat_map(|field| {
+            let member = &field.member;
+            let validate = field.validate(quote!(&self.#member));
             quote! {
-                casper_event_standard::validate_type(&self.#ident)?;
-                vec.extend(self.#ident.to_bytes()?);
+                #validate
+                vec.extend(self.#member.to_bytes()?);
             }
         })
         .collect::<TokenStream2>();
@@ -61,13 +274,12 @@ fn generate_to_bytes_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> Toke
         size += #name_literal.serialized_length();
     };
     sum_serialized_lengths.append_all(
-        fields
-            .iter()
-            .map(|(ident, _)| ident)
-            .map(|ident| quote!(size += self.#ident.serialized_length();)),
+        serialized_fields(fields)
+            .map(|field| &field.member)
+            .map(|member| quote!(size += self.#member.serialized_length();)),
     );
     quote! {
-        impl casper_event_standard::casper_types::bytesrepr::ToBytes for #event_ident {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::ToBytes for #event_ident #ty_generics #where_clause {
             fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
                 let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
                 vec.append(&mut #name_literal.to_bytes()?);
@@ -84,24 +296,35 @@ fn generate_to_bytes_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> Toke
     }
 }
 
-fn generate_from_bytes_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> TokenStream2 {
-    let deserialize_fields = fields
-        .iter()
-        .map(|(ident, _)| ident)
-        .map(|ident| quote!(let (#ident, bytes) = FromBytes::from_bytes(bytes)?;))
-        .collect::<TokenStream2>();
-
-    let construct_struct = fields
-        .iter()
-        .map(|(ident, _)| ident)
-        .map(|ident| quote! { #ident, })
-        .collect::<TokenStream2>();
+fn generate_from_bytes_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    event_name: &EventName,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name_literal = event_name.full_name();
+    let deserialize_fields = generate_deserialize_fields(fields);
+    let construct_struct = generate_construct_fields(fields);
 
     quote! {
-        impl casper_event_standard::casper_types::bytesrepr::FromBytes for #event_ident {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::FromBytes for #event_ident #ty_generics #where_clause {
             fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
-                use casper_event_standard::casper_types::bytesrepr::FromBytes;
-                let (_, bytes): (casper_event_standard::alloc::string::String, &[u8]) = FromBytes::from_bytes(bytes)?;
+                let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                if name != #name_literal {
+                    return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                }
+                #deserialize_fields
+                let value = #event_ident {
+                    #construct_struct
+                };
+                Ok((value, bytes))
+            }
+        }
+
+        impl #impl_generics casper_event_standard::FromBytesLenient for #event_ident #ty_generics #where_clause {
+            fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
                 #deserialize_fields
                 let value = #event_ident {
                     #construct_struct
@@ -112,18 +335,20 @@ fn generate_from_bytes_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> To
     }
 }
 
-fn generate_event_instance_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -> TokenStream2 {
-    let schema_elements = fields
-        .iter()
-        .map(|(ident, ty)| quote! {
-            schema.with_elem(stringify!(#ident), <#ty as casper_event_standard::casper_types::CLTyped>::cl_type());
-        })
-        .collect::<TokenStream2>();
+fn generate_event_instance_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    event_name: &EventName,
+    fields: &[Field],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = event_name.name();
+    let schema_elements = generate_schema_elements(fields);
 
     quote! {
-        impl casper_event_standard::EventInstance for #event_ident {
+        impl #impl_generics casper_event_standard::EventInstance for #event_ident #ty_generics #where_clause {
             fn name() -> casper_event_standard::alloc::string::String {
-                casper_event_standard::alloc::string::String::from(stringify!(#event_ident))
+                casper_event_standard::alloc::string::String::from(#name)
             }
 
             fn schema() -> casper_event_standard::Schema {
@@ -135,24 +360,296 @@ fn generate_event_instance_impl(event_ident: &Ident, fields: &[(Ident, Type)]) -
     }
 }
 
-fn extract_fields(input: DeriveInput) -> Result<Vec<(Ident, Type)>, syn::Error> {
+fn generate_enum_to_bytes_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    variants: &[Variant],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let append_bytes = variants
+        .iter()
+        .map(
+            |Variant {
+                 ident: variant_ident,
+                 name,
+                 fields,
+             }| {
+                let name_literal = name.full_name();
+                let field_idents = generate_field_idents(fields);
+                let append_fields = serialized_fields(fields)
+                    .flat_map(|field| {
+                        let ident = &field.ident;
+                        let validate = field.validate(quote!(#ident));
+                        quote! {
+                            #validate
+                            vec.extend(#ident.to_bytes()?);
+                        }
+                    })
+                    .collect::<TokenStream2>();
+                quote! {
+                    #event_ident::#variant_ident { #field_idents } => {
+                        vec.append(&mut #name_literal.to_bytes()?);
+                        #append_fields
+                    }
+                }
+            },
+        )
+        .collect::<TokenStream2>();
+    let sum_serialized_lengths = variants
+        .iter()
+        .map(
+            |Variant {
+                 ident: variant_ident,
+                 name,
+                 fields,
+             }| {
+                let name_literal = name.full_name();
+                let field_idents = generate_field_idents(fields);
+                let sum_fields = serialized_fields(fields)
+                    .map(|field| &field.ident)
+                    .map(|ident| quote!(size += #ident.serialized_length();))
+                    .collect::<TokenStream2>();
+                quote! {
+                    #event_ident::#variant_ident { #field_idents } => {
+                        size += #name_literal.serialized_length();
+                        #sum_fields
+                    }
+                }
+            },
+        )
+        .collect::<TokenStream2>();
+    quote! {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::ToBytes for #event_ident #ty_generics #where_clause {
+            fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                match self {
+                    #append_bytes
+                }
+                Ok(vec)
+            }
+
+            fn serialized_length(&self) -> usize {
+                let mut size = 0;
+                match self {
+                    #sum_serialized_lengths
+                }
+                size
+            }
+        }
+    }
+}
+
+fn generate_enum_from_bytes_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    variants: &[Variant],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let deserialize_variants = variants
+        .iter()
+        .map(
+            |Variant {
+                 ident: variant_ident,
+                 name,
+                 fields,
+             }| {
+                let name_literal = name.full_name();
+                let deserialize_fields = generate_deserialize_fields(fields);
+                let construct_variant = generate_construct_fields(fields);
+                quote! {
+                    #name_literal => {
+                        #deserialize_fields
+                        let value = #event_ident::#variant_ident {
+                            #construct_variant
+                        };
+                        Ok((value, bytes))
+                    }
+                }
+            },
+        )
+        .collect::<TokenStream2>();
+
+    quote! {
+        impl #impl_generics casper_event_standard::casper_types::bytesrepr::FromBytes for #event_ident #ty_generics #where_clause {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                match name {
+                    #deserialize_variants
+                    _ => Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting),
+                }
+            }
+        }
+    }
+}
+
+fn generate_event_instances_impl(
+    event_ident: &Ident,
+    generics: &Generics,
+    variants: &[Variant],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let push_schemas = variants
+        .iter()
+        .map(|Variant { name, fields, .. }| {
+            let name = name.name();
+            let schema_elements = generate_schema_elements(fields);
+            quote! {
+                let mut schema = casper_event_standard::Schema::new();
+                #schema_elements
+                schemas.push((casper_event_standard::alloc::string::String::from(#name), schema));
+            }
+        })
+        .collect::<TokenStream2>();
+    let names = variants.iter().map(|Variant { name, .. }| name.name());
+
+    quote! {
+        impl #impl_generics casper_event_standard::EventInstances for #event_ident #ty_generics #where_clause {
+            fn schemas() -> casper_event_standard::alloc::vec::Vec<(casper_event_standard::alloc::string::String, casper_event_standard::Schema)> {
+                let mut schemas = casper_event_standard::alloc::vec::Vec::new();
+                #push_schemas
+                schemas
+            }
+
+            fn names() -> casper_event_standard::alloc::vec::Vec<casper_event_standard::alloc::string::String> {
+                casper_event_standard::alloc::vec![#(casper_event_standard::alloc::string::String::from(#names)),*]
+            }
+        }
+    }
+}
+
+fn generate_schema_elements(fields: &[Field]) -> TokenStream2 {
+    serialized_fields(fields)
+        .map(|field| {
+            let name = field.schema_name();
+            let ty = field.schema_type();
+            quote! {
+                schema.with_elem(#name, <#ty as casper_event_standard::casper_types::CLTyped>::cl_type());
+            }
+        })
+        .collect::<TokenStream2>()
+}
+
+fn generate_deserialize_fields(fields: &[Field]) -> TokenStream2 {
+    serialized_fields(fields)
+        .map(|field| &field.ident)
+        .map(|ident| {
+            quote!(let (#ident, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;)
+        })
+        .collect::<TokenStream2>()
+}
+
+fn generate_construct_fields(fields: &[Field]) -> TokenStream2 {
+    fields
+        .iter()
+        .map(|field| {
+            let member = &field.member;
+            let ident = &field.ident;
+            match member {
+                _ if field.attrs.skip => quote! { #member: Default::default(), },
+                Member::Named(_) => quote! { #ident, },
+                Member::Unnamed(_) => quote! { #member: #ident, },
+            }
+        })
+        .collect::<TokenStream2>()
+}
+
+/// Pattern binding the serialized fields of an enum variant.
+fn generate_field_idents(fields: &[Field]) -> TokenStream2 {
+    let mut idents = serialized_fields(fields)
+        .map(|field| &field.ident)
+        .map(|ident| quote! { #ident, })
+        .collect::<TokenStream2>();
+    if fields.iter().any(|field| field.attrs.skip) {
+        idents.append_all(quote! { .. });
+    }
+    idents
+}
+
+fn serialized_fields(fields: &[Field]) -> impl Iterator<Item = &Field> {
+    fields.iter().filter(|field| !field.attrs.skip)
+}
+
+fn extract_fields(input: DeriveInput) -> Result<Vec<Field>, syn::Error> {
     let fields = match input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(named_fields),
-            ..
-        }) => named_fields
-            .named
-            .into_iter()
-            .map(|f| (f.ident.unwrap(), f.ty))
-            .collect::<Vec<_>>(),
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        _ => return Err(syn::Error::new_spanned(input, "Expected a struct.")),
+    };
+    let fields = fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| Field::from_syn(index, field))
+        .collect::<Result<Vec<_>, _>>()?;
+    validate_unique_names(&fields)?;
+    Ok(fields)
+}
+
+/// Rejects schema elements with the same name, e.g. a field renamed to the name of another.
+fn validate_unique_names(fields: &[Field]) -> Result<(), syn::Error> {
+    let mut names = Vec::new();
+    for field in serialized_fields(fields) {
+        let name = field.schema_name_value();
+        if names.contains(&name) {
+            let message = format!("Schema element `{name}` is already defined.");
+            return Err(match &field.attrs.rename {
+                Some(rename) => syn::Error::new(rename.span(), message),
+                None => syn::Error::new_spanned(&field.member, message),
+            });
+        }
+        names.push(name);
+    }
+    Ok(())
+}
+
+fn extract_variants(input: DeriveInput) -> Result<Vec<Variant>, syn::Error> {
+    if attrs::parse_event_attrs(&input.attrs)?.name.is_some() {
+        return Err(syn::Error::new_spanned(
+            input.ident,
+            "Enum events can be renamed only per variant.",
+        ));
+    }
+    let variants = match input.data {
+        Data::Enum(DataEnum { variants, .. }) if !variants.is_empty() => variants,
         _ => {
             return Err(syn::Error::new_spanned(
                 input,
-                "Expected a struct with named fields.",
+                "Expected an enum with at least one variant.",
             ))
         }
     };
-    Ok(fields)
+    let mut result: Vec<Variant> = Vec::new();
+    for variant in variants {
+        let name = EventName::from_syn(variant.ident.clone(), &variant.attrs)?;
+        if result
+            .iter()
+            .any(|other| other.name.value() == name.value())
+        {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "Event with the same name is already defined.",
+            ));
+        }
+        let fields = match variant.fields {
+            Fields::Named(named_fields) => named_fields
+                .named
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| Field::from_syn(index, field))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "Expected an enum variant with named fields.",
+                ))
+            }
+        };
+        validate_unique_names(&fields)?;
+        result.push(Variant {
+            ident: variant.ident,
+            name,
+            fields,
+        });
+    }
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -160,7 +657,9 @@ mod tests {
     use proc_macro2::TokenStream;
     use quote::quote;
 
-    use crate::derive_event_code;
+    use crate::{
+        derive_event_code, event_field::derive_event_field_code, event_set::derive_event_set_code,
+    };
 
     #[test]
     fn test_event_code_generation() {
@@ -179,14 +678,21 @@ mod tests {
                 }
             }
 
+            impl casper_event_standard::HasAny for Transfer {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
             impl casper_event_standard::casper_types::bytesrepr::ToBytes for Transfer {
                 fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
                     let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
                     vec.append(&mut stringify!(event_Transfer).to_bytes()?);
+                    casper_event_standard::assert_no_any!(U256);
                     casper_event_standard::validate_type(&self.amount)?;
                     vec.extend(self.amount.to_bytes()?);
+                    casper_event_standard::assert_no_any!(Key);
                     casper_event_standard::validate_type(&self.from)?;
                     vec.extend(self.from.to_bytes()?);
+                    casper_event_standard::assert_no_any!(Key);
                     casper_event_standard::validate_type(&self.to)?;
                     vec.extend(self.to.to_bytes()?);
                     Ok(vec)
@@ -204,11 +710,24 @@ mod tests {
 
             impl casper_event_standard::casper_types::bytesrepr::FromBytes for Transfer {
                 fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
-                    use casper_event_standard::casper_types::bytesrepr::FromBytes;
-                    let (_, bytes): (casper_event_standard::alloc::string::String, &[u8]) = FromBytes::from_bytes(bytes)?;
-                    let (amount, bytes) = FromBytes::from_bytes(bytes)?;
-                    let (from, bytes) = FromBytes::from_bytes(bytes)?;
-                    let (to, bytes) = FromBytes::from_bytes(bytes)?;
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    if name != stringify!(event_Transfer) {
+                        return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                    }
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (from, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (to, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Transfer { amount, from, to, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::FromBytesLenient for Transfer {
+                fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (from, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (to, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
                     let value = Transfer { amount, from, to, };
                     Ok((value, bytes))
                 }
@@ -232,6 +751,708 @@ mod tests {
         assert_eq_code(result, expected);
     }
 
+    #[test]
+    fn test_enum_event_code_generation() {
+        let input = quote! {
+            pub enum TokenEvent {
+                Mint {
+                    account: Key,
+                    amount: U256
+                },
+                Burn {
+                    account: Key
+                }
+            }
+        };
+        let result = derive_event_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::casper_types::CLTyped for TokenEvent {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Any
+                }
+            }
+
+            impl casper_event_standard::HasAny for TokenEvent {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::ToBytes for TokenEvent {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    match self {
+                        TokenEvent::Mint { account, amount, } => {
+                            vec.append(&mut stringify!(event_Mint).to_bytes()?);
+                            casper_event_standard::assert_no_any!(Key);
+                            casper_event_standard::validate_type(account)?;
+                            vec.extend(account.to_bytes()?);
+                            casper_event_standard::assert_no_any!(U256);
+                            casper_event_standard::validate_type(amount)?;
+                            vec.extend(amount.to_bytes()?);
+                        }
+                        TokenEvent::Burn { account, } => {
+                            vec.append(&mut stringify!(event_Burn).to_bytes()?);
+                            casper_event_standard::assert_no_any!(Key);
+                            casper_event_standard::validate_type(account)?;
+                            vec.extend(account.to_bytes()?);
+                        }
+                    }
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    match self {
+                        TokenEvent::Mint { account, amount, } => {
+                            size += stringify!(event_Mint).serialized_length();
+                            size += account.serialized_length();
+                            size += amount.serialized_length();
+                        }
+                        TokenEvent::Burn { account, } => {
+                            size += stringify!(event_Burn).serialized_length();
+                            size += account.serialized_length();
+                        }
+                    }
+                    size
+                }
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::FromBytes for TokenEvent {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    match name {
+                        stringify!(event_Mint) => {
+                            let (account, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                            let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                            let value = TokenEvent::Mint { account, amount, };
+                            Ok((value, bytes))
+                        }
+                        stringify!(event_Burn) => {
+                            let (account, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                            let value = TokenEvent::Burn { account, };
+                            Ok((value, bytes))
+                        }
+                        _ => Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting),
+                    }
+                }
+            }
+
+            impl casper_event_standard::EventInstances for TokenEvent {
+                fn schemas() -> casper_event_standard::alloc::vec::Vec<(casper_event_standard::alloc::string::String, casper_event_standard::Schema)> {
+                    let mut schemas = casper_event_standard::alloc::vec::Vec::new();
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem(stringify!(account), <Key as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema.with_elem(stringify!(amount), <U256 as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schemas.push((casper_event_standard::alloc::string::String::from(stringify!(Mint)), schema));
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem(stringify!(account), <Key as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schemas.push((casper_event_standard::alloc::string::String::from(stringify!(Burn)), schema));
+                    schemas
+                }
+
+                fn names() -> casper_event_standard::alloc::vec::Vec<casper_event_standard::alloc::string::String> {
+                    casper_event_standard::alloc::vec![casper_event_standard::alloc::string::String::from(stringify!(Mint)), casper_event_standard::alloc::string::String::from(stringify!(Burn))]
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_enum_with_unnamed_fields_is_rejected() {
+        let input = quote! {
+            pub enum TokenEvent {
+                Mint(Key, U256)
+            }
+        };
+        let result = derive_event_code(input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_field_attributes_code_generation() {
+        let input = quote! {
+            pub struct Deposit {
+                #[event(rename = "from_account")]
+                from: Key,
+                #[event(cl_type = U256)]
+                amount: Amount,
+                #[event(skip)]
+                note: Option<String>
+            }
+        };
+        let result = derive_event_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::casper_types::CLTyped for Deposit {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Any
+                }
+            }
+
+            impl casper_event_standard::HasAny for Deposit {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::ToBytes for Deposit {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    vec.append(&mut stringify!(event_Deposit).to_bytes()?);
+                    casper_event_standard::assert_no_any!(Key);
+                    casper_event_standard::validate_type(&self.from)?;
+                    vec.extend(self.from.to_bytes()?);
+                    casper_event_standard::assert_no_any!(U256);
+                    casper_event_standard::validate_cl_type(&<U256 as casper_event_standard::casper_types::CLTyped>::cl_type())?;
+                    vec.extend(self.amount.to_bytes()?);
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    size += stringify!(event_Deposit).serialized_length();
+                    size += self.from.serialized_length();
+                    size += self.amount.serialized_length();
+                    size
+                }
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::FromBytes for Deposit {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    if name != stringify!(event_Deposit) {
+                        return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                    }
+                    let (from, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Deposit { from, amount, note: Default::default(), };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::FromBytesLenient for Deposit {
+                fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    let (from, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Deposit { from, amount, note: Default::default(), };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::EventInstance for Deposit {
+                fn name() -> casper_event_standard::alloc::string::String {
+                    casper_event_standard::alloc::string::String::from(stringify!(Deposit))
+                }
+
+                fn schema() -> casper_event_standard::Schema {
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem("from_account", <Key as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema.with_elem(stringify!(amount), <U256 as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_invalid_field_attributes_are_rejected() {
+        let input = quote! {
+            pub struct Deposit {
+                #[event(skip, rename = "note")]
+                note: Option<String>
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+
+        let input = quote! {
+            pub struct Deposit {
+                #[event(unknown)]
+                note: Option<String>
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_schema_names_are_rejected() {
+        let input = quote! {
+            pub struct Transfer {
+                #[event(rename = "to")]
+                from: Key,
+                to: Key
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+
+        let input = quote! {
+            pub struct Pair(#[event(rename = "1")] Key, Key);
+        };
+        assert!(derive_event_code(input).is_err());
+
+        let input = quote! {
+            pub enum TokenEvent {
+                Mint {
+                    account: Key,
+                    #[event(rename = "account")]
+                    owner: Key
+                }
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+
+        // Skipped fields are not in the schema.
+        let input = quote! {
+            pub struct Transfer {
+                #[event(rename = "to")]
+                from: Key,
+                #[event(skip)]
+                to: Key
+            }
+        };
+        assert!(derive_event_code(input).is_ok());
+    }
+
+    #[test]
+    fn test_renamed_event_code_generation() {
+        let input = quote! {
+            #[event(name = "TokenTransfer")]
+            pub struct Transfer {
+                amount: U256
+            }
+        };
+        let result = derive_event_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::casper_types::CLTyped for Transfer {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Any
+                }
+            }
+
+            impl casper_event_standard::HasAny for Transfer {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::ToBytes for Transfer {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    vec.append(&mut "event_TokenTransfer".to_bytes()?);
+                    casper_event_standard::assert_no_any!(U256);
+                    casper_event_standard::validate_type(&self.amount)?;
+                    vec.extend(self.amount.to_bytes()?);
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    size += "event_TokenTransfer".serialized_length();
+                    size += self.amount.serialized_length();
+                    size
+                }
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::FromBytes for Transfer {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    if name != "event_TokenTransfer" {
+                        return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                    }
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Transfer { amount, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::FromBytesLenient for Transfer {
+                fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Transfer { amount, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::EventInstance for Transfer {
+                fn name() -> casper_event_standard::alloc::string::String {
+                    casper_event_standard::alloc::string::String::from("TokenTransfer")
+                }
+
+                fn schema() -> casper_event_standard::Schema {
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem(stringify!(amount), <U256 as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_invalid_event_names_are_rejected() {
+        let input = quote! {
+            #[event(name = "")]
+            pub struct Transfer {
+                amount: U256
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+
+        let input = quote! {
+            #[event(name = "Events")]
+            pub enum TokenEvent {
+                Mint { amount: U256 }
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+
+        let input = quote! {
+            pub enum TokenEvent {
+                Mint { amount: U256 },
+                #[event(name = "Mint")]
+                Burn { amount: U256 }
+            }
+        };
+        assert!(derive_event_code(input).is_err());
+    }
+
+    #[test]
+    fn test_event_set_code_generation() {
+        let input = quote! {
+            pub enum TokenEvents {
+                Transfer(Transfer),
+                Token(TokenEvent)
+            }
+        };
+        let result = derive_event_set_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::EventInstances for TokenEvents {
+                fn schemas() -> casper_event_standard::alloc::vec::Vec<(casper_event_standard::alloc::string::String, casper_event_standard::Schema)> {
+                    let mut schemas = casper_event_standard::alloc::vec::Vec::new();
+                    schemas.extend(<Transfer as casper_event_standard::EventInstances>::schemas());
+                    schemas.extend(<TokenEvent as casper_event_standard::EventInstances>::schemas());
+                    schemas
+                }
+
+                fn names() -> casper_event_standard::alloc::vec::Vec<casper_event_standard::alloc::string::String> {
+                    let mut names = casper_event_standard::alloc::vec::Vec::new();
+                    names.extend(<Transfer as casper_event_standard::EventInstances>::names());
+                    names.extend(<TokenEvent as casper_event_standard::EventInstances>::names());
+                    names
+                }
+            }
+
+            impl TokenEvents {
+                /// Deserializes an event of any of the variants, based on its name.
+                pub fn from_event_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::Error> {
+                    let name = casper_event_standard::event_name_from_bytes(bytes)?;
+                    if <Transfer as casper_event_standard::EventInstances>::names().iter().any(|expected| expected == name) {
+                        return <Transfer as casper_event_standard::casper_types::bytesrepr::FromBytes>::from_bytes(bytes)
+                            .map(|(event, bytes)| (TokenEvents::Transfer(event), bytes))
+                            .map_err(|_| casper_event_standard::Error::InvalidEvent);
+                    }
+                    if <TokenEvent as casper_event_standard::EventInstances>::names().iter().any(|expected| expected == name) {
+                        return <TokenEvent as casper_event_standard::casper_types::bytesrepr::FromBytes>::from_bytes(bytes)
+                            .map(|(event, bytes)| (TokenEvents::Token(event), bytes))
+                            .map_err(|_| casper_event_standard::Error::InvalidEvent);
+                    }
+                    Err(casper_event_standard::Error::UnexpectedEvent)
+                }
+
+                /// Builds [`Schemas`](casper_event_standard::Schemas) of all the variants.
+                pub fn all_schemas() -> casper_event_standard::Schemas {
+                    casper_event_standard::Schemas::new().with::<Self>()
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_event_set_with_invalid_variants_is_rejected() {
+        let input = quote! {
+            pub enum TokenEvents {
+                Transfer { transfer: Transfer }
+            }
+        };
+        assert!(derive_event_set_code(input).is_err());
+
+        let input = quote! {
+            pub enum TokenEvents {
+                Transfer(Transfer, Mint)
+            }
+        };
+        assert!(derive_event_set_code(input).is_err());
+
+        let input = quote! {
+            pub struct TokenEvents(Transfer);
+        };
+        assert!(derive_event_set_code(input).is_err());
+    }
+
+    #[test]
+    fn test_generic_event_code_generation() {
+        let input = quote! {
+            pub struct Transfer<T: Copy> where T: Default {
+                amount: T
+            }
+        };
+        let result = derive_event_code(input).unwrap();
+        let expected = quote! {
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::casper_types::CLTyped for Transfer<T> where T: Default {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Any
+                }
+            }
+
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::HasAny for Transfer<T> where T: Default {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::casper_types::bytesrepr::ToBytes for Transfer<T> where T: Default {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    vec.append(&mut stringify!(event_Transfer).to_bytes()?);
+                    casper_event_standard::assert_no_any!(T);
+                    casper_event_standard::validate_type(&self.amount)?;
+                    vec.extend(self.amount.to_bytes()?);
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    size += stringify!(event_Transfer).serialized_length();
+                    size += self.amount.serialized_length();
+                    size
+                }
+            }
+
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::casper_types::bytesrepr::FromBytes for Transfer<T> where T: Default {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    if name != stringify!(event_Transfer) {
+                        return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                    }
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Transfer { amount, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::FromBytesLenient for Transfer<T> where T: Default {
+                fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    let (amount, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = Transfer { amount, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl<T: Copy + casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::EventInstance for Transfer<T> where T: Default {
+                fn name() -> casper_event_standard::alloc::string::String {
+                    casper_event_standard::alloc::string::String::from(stringify!(Transfer))
+                }
+
+                fn schema() -> casper_event_standard::Schema {
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem(stringify!(amount), <T as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_tuple_event_code_generation() {
+        let input = quote! {
+            pub struct OwnerChanged(Key, #[event(rename = "new_owner")] Key);
+        };
+        let result = derive_event_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::casper_types::CLTyped for OwnerChanged {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Any
+                }
+            }
+
+            impl casper_event_standard::HasAny for OwnerChanged {
+                type Any = casper_event_standard::ContainsAny;
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::ToBytes for OwnerChanged {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    vec.append(&mut stringify!(event_OwnerChanged).to_bytes()?);
+                    casper_event_standard::assert_no_any!(Key);
+                    casper_event_standard::validate_type(&self.0)?;
+                    vec.extend(self.0.to_bytes()?);
+                    casper_event_standard::assert_no_any!(Key);
+                    casper_event_standard::validate_type(&self.1)?;
+                    vec.extend(self.1.to_bytes()?);
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    size += stringify!(event_OwnerChanged).serialized_length();
+                    size += self.0.serialized_length();
+                    size += self.1.serialized_length();
+                    size
+                }
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::FromBytes for OwnerChanged {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    if name != stringify!(event_OwnerChanged) {
+                        return Err(casper_event_standard::casper_types::bytesrepr::Error::Formatting);
+                    }
+                    let (field_0, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (field_1, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = OwnerChanged { 0: field_0, 1: field_1, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::FromBytesLenient for OwnerChanged {
+                fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (_, bytes) = casper_event_standard::split_full_name(bytes)?;
+                    let (field_0, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (field_1, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = OwnerChanged { 0: field_0, 1: field_1, };
+                    Ok((value, bytes))
+                }
+            }
+
+            impl casper_event_standard::EventInstance for OwnerChanged {
+                fn name() -> casper_event_standard::alloc::string::String {
+                    casper_event_standard::alloc::string::String::from(stringify!(OwnerChanged))
+                }
+
+                fn schema() -> casper_event_standard::Schema {
+                    let mut schema = casper_event_standard::Schema::new();
+                    schema.with_elem(stringify!(0), <Key as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema.with_elem("new_owner", <Key as casper_event_standard::casper_types::CLTyped>::cl_type());
+                    schema
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_event_field_code_generation() {
+        let input = quote! {
+            pub struct TokenMetadata {
+                name: String,
+                symbol: String,
+                decimals: u8,
+                total_supply: U256
+            }
+        };
+        let result = derive_event_field_code(input).unwrap();
+        let expected = quote! {
+            impl casper_event_standard::casper_types::CLTyped for TokenMetadata {
+                fn cl_type() -> casper_event_standard::casper_types::CLType {
+                    casper_event_standard::casper_types::CLType::Tuple3([
+                        casper_event_standard::alloc::boxed::Box::new(<String as casper_event_standard::casper_types::CLTyped>::cl_type()),
+                        casper_event_standard::alloc::boxed::Box::new(<String as casper_event_standard::casper_types::CLTyped>::cl_type()),
+                        casper_event_standard::alloc::boxed::Box::new(casper_event_standard::casper_types::CLType::Tuple2([
+                            casper_event_standard::alloc::boxed::Box::new(<u8 as casper_event_standard::casper_types::CLTyped>::cl_type()),
+                            casper_event_standard::alloc::boxed::Box::new(<U256 as casper_event_standard::casper_types::CLTyped>::cl_type())
+                        ]))
+                    ])
+                }
+            }
+
+            impl casper_event_standard::HasAny for TokenMetadata {
+                type Any = casper_event_standard::NoAny;
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::ToBytes for TokenMetadata {
+                fn to_bytes(&self) -> Result<casper_event_standard::alloc::vec::Vec<u8>, casper_event_standard::casper_types::bytesrepr::Error> {
+                    let mut vec = casper_event_standard::alloc::vec::Vec::with_capacity(self.serialized_length());
+                    casper_event_standard::assert_no_any!(String);
+                    casper_event_standard::validate_type(&self.name)?;
+                    vec.extend(self.name.to_bytes()?);
+                    casper_event_standard::assert_no_any!(String);
+                    casper_event_standard::validate_type(&self.symbol)?;
+                    vec.extend(self.symbol.to_bytes()?);
+                    casper_event_standard::assert_no_any!(u8);
+                    casper_event_standard::validate_type(&self.decimals)?;
+                    vec.extend(self.decimals.to_bytes()?);
+                    casper_event_standard::assert_no_any!(U256);
+                    casper_event_standard::validate_type(&self.total_supply)?;
+                    vec.extend(self.total_supply.to_bytes()?);
+                    Ok(vec)
+                }
+
+                fn serialized_length(&self) -> usize {
+                    let mut size = 0;
+                    size += self.name.serialized_length();
+                    size += self.symbol.serialized_length();
+                    size += self.decimals.serialized_length();
+                    size += self.total_supply.serialized_length();
+                    size
+                }
+            }
+
+            impl casper_event_standard::casper_types::bytesrepr::FromBytes for TokenMetadata {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_event_standard::casper_types::bytesrepr::Error> {
+                    let (name, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (symbol, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (decimals, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let (total_supply, bytes) = casper_event_standard::casper_types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                    let value = TokenMetadata { name, symbol, decimals, total_supply, };
+                    Ok((value, bytes))
+                }
+            }
+        };
+        assert_eq_code(result, expected);
+    }
+
+    #[test]
+    fn test_generic_event_field_code_generation() {
+        let input = quote! {
+            struct Meta<T> {
+                value: T,
+                items: Vec<T>,
+                id: u8
+            }
+        };
+        let result = derive_event_field_code(input).unwrap().to_string();
+        let expected = quote! {
+            impl<T: casper_event_standard::casper_types::CLTyped + casper_event_standard::casper_types::bytesrepr::ToBytes + casper_event_standard::casper_types::bytesrepr::FromBytes> casper_event_standard::HasAny for Meta<T>
+            where
+                T: casper_event_standard::HasAny,
+                Vec<T>: casper_event_standard::HasAny
+            {
+                type Any = < <T as casper_event_standard::HasAny>::Any as casper_event_standard::AnyFlag>::Or< <Vec<T> as casper_event_standard::HasAny>::Any>;
+            }
+        };
+        assert!(result.contains(&expected.to_string()));
+    }
+
+    #[test]
+    fn test_event_field_name_is_rejected() {
+        let input = quote! {
+            #[event(name = "Meta")]
+            struct TokenMetadata {
+                total_supply: U256
+            }
+        };
+        assert!(derive_event_field_code(input).is_err());
+    }
+
+    #[test]
+    fn test_event_field_rename_is_rejected() {
+        let input = quote! {
+            struct TokenMetadata {
+                #[event(rename = "total")]
+                total_supply: U256
+            }
+        };
+        assert!(derive_event_field_code(input).is_err());
+    }
+
     fn assert_eq_code(result: TokenStream, expected: TokenStream) {
         pretty_assertions::assert_eq!(expected.to_string(), result.to_string());
     }
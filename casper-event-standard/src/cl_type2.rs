@@ -1,7 +1,7 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped,
+    CLType, CLTyped, Key, PublicKey, URef, U128, U256, U512,
 };
 
 #[cfg(feature = "serde")]
@@ -142,4 +142,148 @@ fn serialize_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
     Ok(())
 }
 
-// TODO: Tests
+/// Skips a value of the given [`CLType`] and returns the remaining bytes.
+pub(crate) fn skip_value<'a>(
+    cl_type: &CLType,
+    bytes: &'a [u8],
+) -> Result<&'a [u8], bytesrepr::Error> {
+    match cl_type {
+        CLType::Bool => skip::<bool>(bytes),
+        CLType::I32 => skip::<i32>(bytes),
+        CLType::I64 => skip::<i64>(bytes),
+        CLType::U8 => skip::<u8>(bytes),
+        CLType::U32 => skip::<u32>(bytes),
+        CLType::U64 => skip::<u64>(bytes),
+        CLType::U128 => skip::<U128>(bytes),
+        CLType::U256 => skip::<U256>(bytes),
+        CLType::U512 => skip::<U512>(bytes),
+        CLType::Unit => Ok(bytes),
+        CLType::String => skip::<String>(bytes),
+        CLType::Key => skip::<Key>(bytes),
+        CLType::URef => skip::<URef>(bytes),
+        CLType::PublicKey => skip::<PublicKey>(bytes),
+        CLType::Option(cl_type) => match u8::from_bytes(bytes)? {
+            (0, bytes) => Ok(bytes),
+            (1, bytes) => skip_value(cl_type, bytes),
+            _ => Err(bytesrepr::Error::Formatting),
+        },
+        CLType::List(cl_type) => {
+            let (len, mut bytes) = read_len(bytes)?;
+            for _ in 0..len {
+                bytes = skip_value(cl_type, bytes)?;
+            }
+            Ok(bytes)
+        }
+        CLType::ByteArray(len) => {
+            bytesrepr::safe_split_at(bytes, *len as usize).map(|(_, bytes)| bytes)
+        }
+        CLType::Result { ok, err } => match u8::from_bytes(bytes)? {
+            (0, bytes) => skip_value(err, bytes),
+            (1, bytes) => skip_value(ok, bytes),
+            _ => Err(bytesrepr::Error::Formatting),
+        },
+        CLType::Map { key, value } => {
+            let (len, mut bytes) = read_len(bytes)?;
+            for _ in 0..len {
+                bytes = skip_value(key, bytes)?;
+                bytes = skip_value(value, bytes)?;
+            }
+            Ok(bytes)
+        }
+        CLType::Tuple1(cl_type_array) => skip_tuple(cl_type_array, bytes),
+        CLType::Tuple2(cl_type_array) => skip_tuple(cl_type_array, bytes),
+        CLType::Tuple3(cl_type_array) => skip_tuple(cl_type_array, bytes),
+        CLType::Any => Err(bytesrepr::Error::Formatting),
+    }
+}
+
+/// Reads the number of elements of a list or a map.
+///
+/// The length prefix is untrusted, so a length greater than the number of remaining bytes
+/// is rejected before iterating, as if every element took at least one byte.
+/// It bounds the work also for zero-sized elements, e.g. of `List<Unit>`.
+pub(crate) fn read_len(bytes: &[u8]) -> Result<(u32, &[u8]), bytesrepr::Error> {
+    let (len, bytes) = u32::from_bytes(bytes)?;
+    if len as usize > bytes.len() {
+        return Err(bytesrepr::Error::EarlyEndOfStream);
+    }
+    Ok((len, bytes))
+}
+
+fn skip<T: FromBytes>(bytes: &[u8]) -> Result<&[u8], bytesrepr::Error> {
+    T::from_bytes(bytes).map(|(_, bytes)| bytes)
+}
+
+fn skip_tuple<'a>(
+    cl_type_array: &[Box<CLType>],
+    mut bytes: &'a [u8],
+) -> Result<&'a [u8], bytesrepr::Error> {
+    for cl_type in cl_type_array {
+        bytes = skip_value(cl_type, bytes)?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, collections::BTreeMap, string::String, vec, vec::Vec};
+    use casper_types::{
+        bytesrepr::{self, ToBytes},
+        CLType, CLTyped, U256,
+    };
+
+    use super::skip_value;
+
+    fn assert_skips<T: CLTyped + ToBytes>(value: T) {
+        let mut bytes = value.to_bytes().unwrap();
+        bytes.extend([1, 2, 3]);
+        assert_eq!(skip_value(&T::cl_type(), &bytes), Ok(&[1u8, 2, 3][..]));
+    }
+
+    #[test]
+    fn test_skip_value() {
+        assert_skips(true);
+        assert_skips(7u8);
+        assert_skips(U256::from(1_000));
+        assert_skips(String::from("value"));
+        assert_skips(Some(5u64));
+        assert_skips(Option::<u64>::None);
+        assert_skips(vec![1u32, 2, 3]);
+        assert_skips(BTreeMap::from([(String::from("a"), 1u8)]));
+        assert_skips(Result::<u8, String>::Err(String::from("error")));
+        assert_skips((1u8, String::from("b"), Some(true)));
+        assert_skips([9u8; 4]);
+    }
+
+    #[test]
+    fn test_skip_truncated_value() {
+        let bytes = String::from("value").to_bytes().unwrap();
+        assert!(skip_value(&CLType::String, &bytes[..bytes.len() - 1]).is_err());
+        assert_eq!(
+            skip_value(&CLType::Any, &bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn test_skip_huge_list_of_units() {
+        // 4 billion zero-sized elements claimed by a 4 byte length prefix.
+        let bytes = u32::MAX.to_bytes().unwrap();
+        let ty = CLType::List(Box::new(CLType::Unit));
+        assert_eq!(
+            skip_value(&ty, &bytes),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+
+        let ty = CLType::Map {
+            key: Box::new(CLType::Unit),
+            value: Box::new(CLType::Unit),
+        };
+        let mut bytes = u32::MAX.to_bytes().unwrap();
+        bytes.extend(Vec::from([0u8; 8]));
+        assert_eq!(
+            skip_value(&ty, &bytes),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+    }
+}
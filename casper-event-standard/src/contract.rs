@@ -1,51 +1,456 @@
 use crate::{
-    schema::Schemas, CES_VERSION, CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH, EVENTS_SCHEMA,
-};
-use alloc::string::ToString;
-use casper_contract::contract_api::{runtime, storage};
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{
-    bytesrepr::{Bytes, ToBytes},
-    ApiError, URef,
+    error::Error,
+    host::{with_host, Host},
+    keys::EventKeys,
+    metadata::{EventsMetadata, Layout},
+    schema::Schemas,
+    CES_VERSION,
 };
+use alloc::{string::ToString, vec::Vec};
+use casper_types::bytesrepr::{Bytes, ToBytes};
 
 /// Initializes events-releated named keys and stores [`Schemas`].
 ///
 /// It should be called during the contract initialization.
+/// Reverts with the error of [`try_init`].
 ///
 /// [`Schemas`]: crate::Schema
 pub fn init(schemas: Schemas) {
-    expect_no_key(EVENTS_LENGTH);
-    expect_no_key(EVENTS_SCHEMA);
-    storage::new_dictionary(EVENTS_DICT).unwrap_or_revert();
-    runtime::put_key(EVENTS_LENGTH, storage::new_uref(0u32).into());
-    runtime::put_key(EVENTS_SCHEMA, storage::new_uref(schemas).into());
-    runtime::put_key(CES_VERSION_KEY, storage::new_uref(CES_VERSION).into());
+    with_host(|host| {
+        let result = init_events(host, &EventKeys::default(), schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`init`], but returns an error instead of reverting.
+///
+/// Fails with [`Error::AlreadyInitialized`] if any of the events-related
+/// named keys already exists.
+///
+/// [`Error::AlreadyInitialized`]: crate::Error::AlreadyInitialized
+pub fn try_init(schemas: Schemas) -> Result<(), Error> {
+    with_host(|host| init_events(host, &EventKeys::default(), schemas))
+}
+
+/// Initializes named keys of the event stream identified by `prefix`
+/// and stores its [`Schemas`].
+///
+/// See [`EventKeys::with_prefix`] for the names of the keys.
+///
+/// [`Schemas`]: crate::Schemas
+pub fn init_with_prefix(prefix: &str, schemas: Schemas) {
+    with_host(|host| {
+        let result = init_events(host, &EventKeys::with_prefix(prefix), schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`init_with_prefix`], but returns an error instead of reverting.
+pub fn try_init_with_prefix(prefix: &str, schemas: Schemas) -> Result<(), Error> {
+    with_host(|host| init_events(host, &EventKeys::with_prefix(prefix), schemas))
+}
+
+/// Initializes the event stream identified by `prefix`, storing its
+/// named keys in the given [`Layout`].
+///
+/// The empty prefix initializes the default stream. All the other functions
+/// detect the layout of an initialized stream, so only `init` needs to choose it.
+///
+/// [`Layout`]: crate::Layout
+pub fn init_with_layout(prefix: &str, layout: Layout, schemas: Schemas) {
+    with_host(|host| {
+        let keys = EventKeys::with_prefix(prefix);
+        let result = init_events_with_layout(host, &keys, layout, schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`init_with_layout`], but returns an error instead of reverting.
+pub fn try_init_with_layout(prefix: &str, layout: Layout, schemas: Schemas) -> Result<(), Error> {
+    with_host(|host| {
+        let keys = EventKeys::with_prefix(prefix);
+        init_events_with_layout(host, &keys, layout, schemas)
+    })
+}
+
+/// Registers new event [`Schemas`] in the already initialized contract.
+///
+/// It should be called during the contract upgrade.
+/// The emitted events and the events counter are not modified.
+/// Reverts with the error of [`try_register_schemas`].
+///
+/// [`Schemas`]: crate::Schemas
+pub fn register_schemas(schemas: Schemas) {
+    with_host(|host| {
+        let result = register_event_schemas(host, &EventKeys::default(), schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`register_schemas`], but returns an error instead of reverting.
+///
+/// Fails with [`Error::NotInitialized`] if the events are not initialized
+/// and with [`Error::IncompatibleSchema`] if an event is already
+/// registered with a different [`Schema`].
+///
+/// [`Schema`]: crate::Schema
+/// [`Error::NotInitialized`]: crate::Error::NotInitialized
+/// [`Error::IncompatibleSchema`]: crate::Error::IncompatibleSchema
+pub fn try_register_schemas(schemas: Schemas) -> Result<(), Error> {
+    with_host(|host| register_event_schemas(host, &EventKeys::default(), schemas))
+}
+
+/// Same as [`register_schemas`], but for the event stream identified by `prefix`.
+pub fn register_schemas_with_prefix(prefix: &str, schemas: Schemas) {
+    with_host(|host| {
+        let result = register_event_schemas(host, &EventKeys::with_prefix(prefix), schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`register_schemas_with_prefix`], but returns an error instead of reverting.
+pub fn try_register_schemas_with_prefix(prefix: &str, schemas: Schemas) -> Result<(), Error> {
+    with_host(|host| register_event_schemas(host, &EventKeys::with_prefix(prefix), schemas))
 }
 
 /// Emits an event.
+///
+/// Reverts with the error of [`try_emit`].
 pub fn emit<T: ToBytes>(event: T) {
-    let length_key = runtime::get_key(EVENTS_LENGTH).unwrap_or_revert();
-    let length_uref = length_key.try_into().unwrap_or_revert();
-    let lenght: u32 = storage::read(length_uref)
-        .unwrap_or_revert()
-        .unwrap_or_revert();
-    let seed = event_dict_seed();
-    let event_bytes = event.to_bytes().unwrap_or_revert();
-    let event_bytes: Bytes = event_bytes.into();
-    storage::dictionary_put(seed, &lenght.to_string(), event_bytes);
-    storage::write(length_uref, lenght + 1);
-}
-
-fn event_dict_seed() -> URef {
-    runtime::get_key(EVENTS_DICT)
-        .unwrap_or_revert()
-        .try_into()
-        .unwrap_or_revert()
-}
-
-fn expect_no_key(name: &str) {
-    if runtime::has_key(name) {
-        runtime::revert(ApiError::InvalidArgument);
+    with_host(|host| {
+        let result = emit_event(host, &EventKeys::default(), event);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit`], but returns an error instead of reverting.
+///
+/// Fails with [`Error::NotInitialized`] if the events are not initialized
+/// and with [`Error::Serialization`] if the event can't be serialized.
+///
+/// [`Error::NotInitialized`]: crate::Error::NotInitialized
+/// [`Error::Serialization`]: crate::Error::Serialization
+pub fn try_emit<T: ToBytes>(event: T) -> Result<(), Error> {
+    with_host(|host| emit_event(host, &EventKeys::default(), event))
+}
+
+/// Emits an event to the event stream identified by `prefix`.
+pub fn emit_to<T: ToBytes>(prefix: &str, event: T) {
+    with_host(|host| {
+        let result = emit_event(host, &EventKeys::with_prefix(prefix), event);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_to`], but returns an error instead of reverting.
+pub fn try_emit_to<T: ToBytes>(prefix: &str, event: T) -> Result<(), Error> {
+    with_host(|host| emit_event(host, &EventKeys::with_prefix(prefix), event))
+}
+
+/// Emits an event, initializing the events first if [`init`] was never called.
+///
+/// It lets a contract upgraded from a version without events start emitting
+/// without a migration entry point. `schemas` is called only on the first emit,
+/// e.g. `emit_or_init(event, Schemas::new)` initializes the events with no schemas.
+/// Reverts with the error of [`try_emit_or_init`].
+pub fn emit_or_init<T: ToBytes, F: FnOnce() -> Schemas>(event: T, schemas: F) {
+    with_host(|host| {
+        let result = emit_or_init_event(host, &EventKeys::default(), event, schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_or_init`], but returns an error instead of reverting.
+pub fn try_emit_or_init<T: ToBytes, F: FnOnce() -> Schemas>(
+    event: T,
+    schemas: F,
+) -> Result<(), Error> {
+    with_host(|host| emit_or_init_event(host, &EventKeys::default(), event, schemas))
+}
+
+/// Same as [`emit_or_init`], but for the event stream identified by `prefix`.
+pub fn emit_or_init_to<T: ToBytes, F: FnOnce() -> Schemas>(prefix: &str, event: T, schemas: F) {
+    with_host(|host| {
+        let result = emit_or_init_event(host, &EventKeys::with_prefix(prefix), event, schemas);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_or_init_to`], but returns an error instead of reverting.
+pub fn try_emit_or_init_to<T: ToBytes, F: FnOnce() -> Schemas>(
+    prefix: &str,
+    event: T,
+    schemas: F,
+) -> Result<(), Error> {
+    with_host(|host| emit_or_init_event(host, &EventKeys::with_prefix(prefix), event, schemas))
+}
+
+/// Emits multiple events in order.
+///
+/// The events counter and the events dictionary are looked up only once,
+/// no matter how many events are emitted. An empty batch doesn't touch the storage.
+pub fn emit_batch<T: ToBytes, I: IntoIterator<Item = T>>(events: I) {
+    with_host(|host| {
+        let result = emit_event_batch(host, &EventKeys::default(), events);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_batch`], but returns an error instead of reverting.
+///
+/// All the events are serialized before the first one is stored,
+/// so no event is emitted if any of them fails.
+pub fn try_emit_batch<T: ToBytes, I: IntoIterator<Item = T>>(events: I) -> Result<(), Error> {
+    with_host(|host| emit_event_batch(host, &EventKeys::default(), events))
+}
+
+/// Same as [`emit_batch`], but for the event stream identified by `prefix`.
+pub fn emit_batch_to<T: ToBytes, I: IntoIterator<Item = T>>(prefix: &str, events: I) {
+    with_host(|host| {
+        let result = emit_event_batch(host, &EventKeys::with_prefix(prefix), events);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_batch_to`], but returns an error instead of reverting.
+pub fn try_emit_batch_to<T: ToBytes, I: IntoIterator<Item = T>>(
+    prefix: &str,
+    events: I,
+) -> Result<(), Error> {
+    with_host(|host| emit_event_batch(host, &EventKeys::with_prefix(prefix), events))
+}
+
+/// Emits an event after checking it against the stored [`Schemas`].
+///
+/// Reverts with the error of [`try_emit_checked`].
+///
+/// [`Schemas`]: crate::Schemas
+pub fn emit_checked<T: ToBytes>(event: T) {
+    with_host(|host| {
+        let result = emit_checked_event(host, &EventKeys::default(), event);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_checked`], but returns an error instead of reverting.
+///
+/// Besides the errors of [`try_emit`], fails with [`Error::UnknownEvent`]
+/// if the event is not registered and with [`Error::InvalidEvent`]
+/// if it doesn't match its [`Schema`].
+///
+/// [`Schema`]: crate::Schema
+/// [`Error::UnknownEvent`]: crate::Error::UnknownEvent
+/// [`Error::InvalidEvent`]: crate::Error::InvalidEvent
+pub fn try_emit_checked<T: ToBytes>(event: T) -> Result<(), Error> {
+    with_host(|host| emit_checked_event(host, &EventKeys::default(), event))
+}
+
+/// Same as [`emit_checked`], but for the event stream identified by `prefix`.
+pub fn emit_checked_to<T: ToBytes>(prefix: &str, event: T) {
+    with_host(|host| {
+        let result = emit_checked_event(host, &EventKeys::with_prefix(prefix), event);
+        unwrap_or_revert(host, result)
+    })
+}
+
+/// Same as [`emit_checked_to`], but returns an error instead of reverting.
+pub fn try_emit_checked_to<T: ToBytes>(prefix: &str, event: T) -> Result<(), Error> {
+    with_host(|host| emit_checked_event(host, &EventKeys::with_prefix(prefix), event))
+}
+
+fn init_events<H: Host>(host: &mut H, keys: &EventKeys, schemas: Schemas) -> Result<(), Error> {
+    init_events_with_layout(host, keys, Layout::default(), schemas)
+}
+
+fn init_events_with_layout<H: Host>(
+    host: &mut H,
+    keys: &EventKeys,
+    layout: Layout,
+    schemas: Schemas,
+) -> Result<(), Error> {
+    expect_no_key(host, keys.events_dict())?;
+    expect_no_key(host, keys.events_length())?;
+    expect_no_key(host, keys.events_schema())?;
+    expect_no_key(host, keys.events_metadata())?;
+    expect_no_key(host, keys.ces_version())?;
+    host.new_dictionary(keys.events_dict())?;
+    match layout {
+        Layout::NamedKeys => {
+            host.put_value(keys.events_length(), 0u32)?;
+            host.put_value(keys.events_schema(), schemas)?;
+            host.put_value(keys.ces_version(), CES_VERSION)
+        }
+        Layout::Metadata => {
+            let metadata = EventsMetadata {
+                ces_version: CES_VERSION.to_string(),
+                events_length: 0,
+                schemas,
+            };
+            host.put_value(keys.events_metadata(), metadata)
+        }
+    }
+}
+
+fn register_event_schemas<H: Host>(
+    host: &mut H,
+    keys: &EventKeys,
+    schemas: Schemas,
+) -> Result<(), Error> {
+    if let Some(schemas_key) = host.get_key(keys.events_schema())? {
+        let mut stored_schemas: Schemas = host.read_value(&schemas_key)?;
+        stored_schemas.merge(schemas)?;
+        return host.write_value(&schemas_key, stored_schemas);
+    }
+    let metadata_key = get_initialized_key(host, keys.events_metadata())?;
+    let mut metadata: EventsMetadata = host.read_value(&metadata_key)?;
+    metadata.schemas.merge(schemas)?;
+    host.write_value(&metadata_key, metadata)
+}
+
+fn emit_event<H: Host, T: ToBytes>(host: &mut H, keys: &EventKeys, event: T) -> Result<(), Error> {
+    let event_bytes = serialize(event)?;
+    put_event(host, keys, event_bytes)
+}
+
+fn emit_or_init_event<H: Host, T: ToBytes, F: FnOnce() -> Schemas>(
+    host: &mut H,
+    keys: &EventKeys,
+    event: T,
+    schemas: F,
+) -> Result<(), Error> {
+    let event_bytes = serialize(event)?;
+    if !host.has_key(keys.events_length()) && !host.has_key(keys.events_metadata()) {
+        init_events(host, keys, schemas())?;
+    }
+    put_event(host, keys, event_bytes)
+}
+
+fn emit_event_batch<H: Host, T: ToBytes, I: IntoIterator<Item = T>>(
+    host: &mut H,
+    keys: &EventKeys,
+    events: I,
+) -> Result<(), Error> {
+    let events = events
+        .into_iter()
+        .map(serialize)
+        .collect::<Result<Vec<_>, _>>()?;
+    if events.is_empty() {
+        return Ok(());
+    }
+    update_length(host, keys, |host, mut length| {
+        let events_dict = get_initialized_key(host, keys.events_dict())?;
+        for event_bytes in events {
+            host.dictionary_put(&events_dict, &length.to_string(), event_bytes)?;
+            length += 1;
+        }
+        Ok(length)
+    })
+}
+
+fn emit_checked_event<H: Host, T: ToBytes>(
+    host: &mut H,
+    keys: &EventKeys,
+    event: T,
+) -> Result<(), Error> {
+    let event_bytes = serialize(event)?;
+    read_schemas(host, keys)?.validate(&event_bytes)?;
+    put_event(host, keys, event_bytes)
+}
+
+fn serialize<T: ToBytes>(event: T) -> Result<Bytes, Error> {
+    event
+        .to_bytes()
+        .map(Bytes::from)
+        .map_err(|_| Error::Serialization)
+}
+
+fn put_event<H: Host>(host: &mut H, keys: &EventKeys, event_bytes: Bytes) -> Result<(), Error> {
+    update_length(host, keys, |host, length| {
+        let events_dict = get_initialized_key(host, keys.events_dict())?;
+        host.dictionary_put(&events_dict, &length.to_string(), event_bytes)?;
+        Ok(length + 1)
+    })
+}
+
+/// Replaces the events counter with the result of `f`, in either [`Layout`].
+///
+/// The counter of the default [`Layout::NamedKeys`] is tried first,
+/// so only the streams in [`Layout::Metadata`] pay for the second lookup.
+fn update_length<H: Host, F: FnOnce(&mut H, u32) -> Result<u32, Error>>(
+    host: &mut H,
+    keys: &EventKeys,
+    f: F,
+) -> Result<(), Error> {
+    if let Some(length_key) = host.get_key(keys.events_length())? {
+        let length = host.read_value(&length_key)?;
+        let length = f(host, length)?;
+        return host.write_value(&length_key, length);
+    }
+    let metadata_key = get_initialized_key(host, keys.events_metadata())?;
+    let mut metadata: EventsMetadata = host.read_value(&metadata_key)?;
+    metadata.events_length = f(host, metadata.events_length)?;
+    host.write_value(&metadata_key, metadata)
+}
+
+/// Reads the events counter in either [`Layout`].
+#[cfg(all(not(target_arch = "wasm32"), feature = "test-support"))]
+pub(crate) fn read_length<H: Host>(host: &H, keys: &EventKeys) -> Result<u32, Error> {
+    match host.get_key(keys.events_length())? {
+        Some(length_key) => host.read_value(&length_key),
+        None => Ok(read_metadata(host, keys)?.events_length),
+    }
+}
+
+/// Reads the stored [`Schemas`] in either [`Layout`].
+pub(crate) fn read_schemas<H: Host>(host: &H, keys: &EventKeys) -> Result<Schemas, Error> {
+    match host.get_key(keys.events_schema())? {
+        Some(schemas_key) => host.read_value(&schemas_key),
+        None => Ok(read_metadata(host, keys)?.schemas),
+    }
+}
+
+fn read_metadata<H: Host>(host: &H, keys: &EventKeys) -> Result<EventsMetadata, Error> {
+    host.read_value(&get_initialized_key(host, keys.events_metadata())?)
+}
+
+/// Resolves one of the events-related named keys, which exist once the events are initialized.
+fn get_initialized_key<H: Host>(host: &H, name: &str) -> Result<H::Key, Error> {
+    host.get_key(name)?.ok_or(Error::NotInitialized)
+}
+
+fn expect_no_key<H: Host>(host: &H, name: &str) -> Result<(), Error> {
+    if host.has_key(name) {
+        return Err(Error::AlreadyInitialized);
+    }
+    Ok(())
+}
+
+fn unwrap_or_revert<H: Host>(host: &H, result: Result<(), Error>) {
+    if let Err(error) = result {
+        host.revert(error.into());
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use crate::{
+        error::Error, host::Host, in_memory::InMemoryHost, keys::EventKeys, metadata::Layout,
+        schema::Schemas, CES_VERSION,
+    };
+
+    use super::init_events_with_layout;
+
+    #[test]
+    fn test_init_over_ces_version() {
+        let keys = EventKeys::default();
+        for layout in [Layout::NamedKeys, Layout::Metadata] {
+            let mut host = InMemoryHost::default();
+            host.put_value(keys.ces_version(), CES_VERSION).unwrap();
+            assert_eq!(
+                init_events_with_layout(&mut host, &keys, layout, Schemas::new()),
+                Err(Error::AlreadyInitialized)
+            );
+            assert!(!host.has_key(keys.events_dict()));
+        }
     }
 }
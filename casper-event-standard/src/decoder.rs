@@ -0,0 +1,224 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, Key, PublicKey, URef, U128, U256, U512,
+};
+use core::fmt;
+
+use crate::{cl_type2, schema::Schemas, split_full_name, EVENT_PREFIX};
+
+/// A value of an event field, decoded without knowing its Rust type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    U128(U128),
+    U256(U256),
+    U512(U512),
+    Unit,
+    String(String),
+    Key(Key),
+    URef(URef),
+    PublicKey(PublicKey),
+    Option(Option<Box<Value>>),
+    List(Vec<Value>),
+    ByteArray(Vec<u8>),
+    Result(Result<Box<Value>, Box<Value>>),
+    /// Entries in the serialization order.
+    Map(Vec<(Value, Value)>),
+    /// Elements of `Tuple1`, `Tuple2` or `Tuple3`.
+    Tuple(Vec<Value>),
+}
+
+/// An event decoded using its [`Schema`](crate::Schema).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+    name: String,
+    fields: Vec<(String, Value)>,
+}
+
+impl DecodedEvent {
+    /// Returns the name of the event without `event_` prefix.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the named fields in the schema order.
+    pub fn fields(&self) -> &[(String, Value)] {
+        &self.fields
+    }
+
+    /// Returns the value of the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The error returned when an event can't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The bytes don't match the expected type.
+    Bytesrepr(bytesrepr::Error),
+    /// The event name doesn't start with `event_` prefix.
+    InvalidName(String),
+    /// There is no [`Schema`](crate::Schema) for the event.
+    UnknownEvent(String),
+    /// The [`Schema`](crate::Schema) contains a type that can't be decoded.
+    UnsupportedType(CLType),
+    /// Not all the bytes were consumed by the event fields.
+    TrailingBytes(usize),
+}
+
+impl From<bytesrepr::Error> for DecodeError {
+    fn from(error: bytesrepr::Error) -> Self {
+        DecodeError::Bytesrepr(error)
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Bytesrepr(error) => write!(f, "Invalid bytes: {}", error),
+            DecodeError::InvalidName(name) => write!(f, "Invalid event name: {}", name),
+            DecodeError::UnknownEvent(name) => write!(f, "Unknown event: {}", name),
+            DecodeError::UnsupportedType(ty) => write!(f, "Unsupported type: {:?}", ty),
+            DecodeError::TrailingBytes(len) => write!(f, "{} trailing bytes", len),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Decodes an event using the matching [`Schema`](crate::Schema) from `schemas`.
+///
+/// All the bytes have to be consumed.
+pub fn decode_event(schemas: &Schemas, bytes: &[u8]) -> Result<DecodedEvent, DecodeError> {
+    let (full_name, mut bytes) = split_full_name(bytes)?;
+    let name = full_name
+        .strip_prefix(EVENT_PREFIX)
+        .ok_or_else(|| DecodeError::InvalidName(String::from(full_name)))?;
+    let schema = schemas
+        .0
+        .get(name)
+        .ok_or_else(|| DecodeError::UnknownEvent(String::from(name)))?;
+
+    let mut fields = Vec::new();
+    for (field_name, ty) in schema.elems() {
+        let (value, remainder) = decode_value(&ty.0, bytes)?;
+        fields.push((field_name.clone(), value));
+        bytes = remainder;
+    }
+    if !bytes.is_empty() {
+        return Err(DecodeError::TrailingBytes(bytes.len()));
+    }
+
+    Ok(DecodedEvent {
+        name: String::from(name),
+        fields,
+    })
+}
+
+/// Decodes a single value of the given [`CLType`].
+///
+/// Returns the value and the remaining bytes. A list or a map can't have
+/// more elements than the remaining bytes, even if the elements are zero-sized.
+pub fn decode_value<'a>(ty: &CLType, bytes: &'a [u8]) -> Result<(Value, &'a [u8]), DecodeError> {
+    match ty {
+        CLType::Bool => parse(bytes, Value::Bool),
+        CLType::I32 => parse(bytes, Value::I32),
+        CLType::I64 => parse(bytes, Value::I64),
+        CLType::U8 => parse(bytes, Value::U8),
+        CLType::U32 => parse(bytes, Value::U32),
+        CLType::U64 => parse(bytes, Value::U64),
+        CLType::U128 => parse(bytes, Value::U128),
+        CLType::U256 => parse(bytes, Value::U256),
+        CLType::U512 => parse(bytes, Value::U512),
+        CLType::Unit => Ok((Value::Unit, bytes)),
+        CLType::String => parse(bytes, Value::String),
+        CLType::Key => parse(bytes, Value::Key),
+        CLType::URef => parse(bytes, Value::URef),
+        CLType::PublicKey => parse(bytes, Value::PublicKey),
+        CLType::Option(ty) => {
+            let (tag, bytes) = u8::from_bytes(bytes)?;
+            match tag {
+                0 => Ok((Value::Option(None), bytes)),
+                1 => {
+                    let (value, bytes) = decode_value(ty, bytes)?;
+                    Ok((Value::Option(Some(Box::new(value))), bytes))
+                }
+                _ => Err(bytesrepr::Error::Formatting.into()),
+            }
+        }
+        CLType::List(ty) => {
+            let (len, mut bytes) = cl_type2::read_len(bytes)?;
+            let mut values = Vec::new();
+            for _ in 0..len {
+                let (value, remainder) = decode_value(ty, bytes)?;
+                values.push(value);
+                bytes = remainder;
+            }
+            Ok((Value::List(values), bytes))
+        }
+        CLType::ByteArray(len) => {
+            let (value, bytes) = bytesrepr::safe_split_at(bytes, *len as usize)?;
+            Ok((Value::ByteArray(value.to_vec()), bytes))
+        }
+        CLType::Result { ok, err } => {
+            let (tag, bytes) = u8::from_bytes(bytes)?;
+            match tag {
+                0 => {
+                    let (value, bytes) = decode_value(err, bytes)?;
+                    Ok((Value::Result(Err(Box::new(value))), bytes))
+                }
+                1 => {
+                    let (value, bytes) = decode_value(ok, bytes)?;
+                    Ok((Value::Result(Ok(Box::new(value))), bytes))
+                }
+                _ => Err(bytesrepr::Error::Formatting.into()),
+            }
+        }
+        CLType::Map { key, value } => {
+            let (len, mut bytes) = cl_type2::read_len(bytes)?;
+            let mut entries = Vec::new();
+            for _ in 0..len {
+                let (k, remainder) = decode_value(key, bytes)?;
+                let (v, remainder) = decode_value(value, remainder)?;
+                entries.push((k, v));
+                bytes = remainder;
+            }
+            Ok((Value::Map(entries), bytes))
+        }
+        CLType::Tuple1(types) => decode_tuple(types, bytes),
+        CLType::Tuple2(types) => decode_tuple(types, bytes),
+        CLType::Tuple3(types) => decode_tuple(types, bytes),
+        CLType::Any => Err(DecodeError::UnsupportedType(CLType::Any)),
+    }
+}
+
+fn parse<T: FromBytes>(
+    bytes: &[u8],
+    variant: fn(T) -> Value,
+) -> Result<(Value, &[u8]), DecodeError> {
+    let (value, bytes) = T::from_bytes(bytes)?;
+    Ok((variant(value), bytes))
+}
+
+fn decode_tuple<'a>(
+    types: &[Box<CLType>],
+    mut bytes: &'a [u8],
+) -> Result<(Value, &'a [u8]), DecodeError> {
+    let mut values = Vec::new();
+    for ty in types {
+        let (value, remainder) = decode_value(ty, bytes)?;
+        values.push(value);
+        bytes = remainder;
+    }
+    Ok((Value::Tuple(values), bytes))
+}
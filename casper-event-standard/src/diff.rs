@@ -0,0 +1,193 @@
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cl_type2::CLType2,
+    schema::{Schema, Schemas},
+};
+
+/// The differences between two versions of [`Schemas`].
+#[derive(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SchemasDiff {
+    /// Events present only in the new version.
+    pub added: Vec<String>,
+    /// Events present only in the old version.
+    pub removed: Vec<String>,
+    /// Field changes of events present in both versions.
+    pub changed: BTreeMap<String, Vec<FieldChange>>,
+}
+
+/// A change of a single field of an event.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FieldChange {
+    /// The field is present only in the new version.
+    Added { name: String, ty: CLType2 },
+    /// The field is present only in the old version.
+    Removed { name: String, ty: CLType2 },
+    /// The field moved relative to the other fields present in both versions.
+    Reordered {
+        name: String,
+        from: usize,
+        to: usize,
+    },
+    /// The type of the field changed.
+    TypeChanged {
+        name: String,
+        from: CLType2,
+        to: CLType2,
+    },
+}
+
+/// The verdict on replacing old [`Schemas`] with the new ones.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Compatibility {
+    /// Nothing changed.
+    Identical,
+    /// Only new events were added, so the already emitted events
+    /// can still be decoded.
+    Compatible,
+    /// An event was removed or changed.
+    Incompatible,
+}
+
+impl SchemasDiff {
+    /// Returns `true` if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Returns the [`Compatibility`] verdict.
+    pub fn compatibility(&self) -> Compatibility {
+        if !self.removed.is_empty() || !self.changed.is_empty() {
+            Compatibility::Incompatible
+        } else if !self.added.is_empty() {
+            Compatibility::Compatible
+        } else {
+            Compatibility::Identical
+        }
+    }
+}
+
+pub(crate) fn diff_schemas(old: &Schemas, new: &Schemas) -> SchemasDiff {
+    let added = new
+        .0
+        .keys()
+        .filter(|name| !old.0.contains_key(*name))
+        .cloned()
+        .collect();
+    let removed = old
+        .0
+        .keys()
+        .filter(|name| !new.0.contains_key(*name))
+        .cloned()
+        .collect();
+    let changed = old
+        .0
+        .iter()
+        .filter_map(|(name, old_schema)| {
+            let changes = diff_schema(old_schema, new.0.get(name)?);
+            (!changes.is_empty()).then(|| (name.clone(), changes))
+        })
+        .collect();
+    SchemasDiff {
+        added,
+        removed,
+        changed,
+    }
+}
+
+pub(crate) fn diff_schema(old: &Schema, new: &Schema) -> Vec<FieldChange> {
+    let old = old.elems();
+    let new = new.elems();
+    let position = |elems: &[(String, CLType2)], name: &str| {
+        elems.iter().position(|(elem_name, _)| elem_name == name)
+    };
+    let mut changes = Vec::new();
+
+    for (name, ty) in old {
+        if position(new, name).is_none() {
+            changes.push(FieldChange::Removed {
+                name: name.clone(),
+                ty: ty.clone(),
+            });
+        }
+    }
+    for (name, ty) in new {
+        if position(old, name).is_none() {
+            changes.push(FieldChange::Added {
+                name: name.clone(),
+                ty: ty.clone(),
+            });
+        }
+    }
+
+    let old_common = old
+        .iter()
+        .filter(|(name, _)| position(new, name).is_some())
+        .collect::<Vec<_>>();
+    let new_common = new
+        .iter()
+        .filter(|(name, _)| position(old, name).is_some())
+        .collect::<Vec<_>>();
+    let moved = moved_fields(&old_common, &new_common);
+    for (old_index, (name, old_ty)) in old_common.iter().enumerate() {
+        let new_ty = new_common
+            .iter()
+            .find(|(new_name, _)| new_name == name)
+            .map_or(old_ty, |(_, new_ty)| new_ty);
+        if moved[old_index] {
+            changes.push(FieldChange::Reordered {
+                name: name.clone(),
+                from: position(old, name).unwrap_or_default(),
+                to: position(new, name).unwrap_or_default(),
+            });
+        }
+        if old_ty != new_ty {
+            changes.push(FieldChange::TypeChanged {
+                name: name.clone(),
+                from: old_ty.clone(),
+                to: new_ty.clone(),
+            });
+        }
+    }
+    changes
+}
+
+/// Marks the fields of `old` that moved relative to the others in `new`.
+///
+/// Both sides have the same fields. The fields of their longest common subsequence
+/// kept their relative order, so only the rest is reported, e.g. moving `a`
+/// in `[a, b, c]` to the end marks only `a`, not all three.
+fn moved_fields(old: &[&(String, CLType2)], new: &[&(String, CLType2)]) -> Vec<bool> {
+    // `lcs[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i].0 == new[j].0 {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut moved = vec![true; old.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i].0 == new[j].0 {
+            moved[i] = false;
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    moved
+}
@@ -0,0 +1,78 @@
+use core::fmt;
+
+use casper_types::ApiError;
+
+/// Errors of the events-related operations.
+///
+/// In the contract they are turned into [`ApiError::User`] with the code
+/// equal to the discriminant:
+///
+/// | Error                  | Code    |
+/// |------------------------|---------|
+/// | [`UnknownEvent`]       | `64000` |
+/// | [`InvalidEvent`]       | `64001` |
+/// | [`IncompatibleSchema`] | `64002` |
+/// | [`DuplicateEvent`]     | `64003` |
+/// | [`UnexpectedEvent`]    | `64004` |
+/// | [`NotInitialized`]     | `64005` |
+/// | [`AlreadyInitialized`] | `64006` |
+/// | [`Serialization`]      | `64007` |
+/// | [`InvalidNamedKey`]    | `64008` |
+///
+/// [`UnknownEvent`]: Error::UnknownEvent
+/// [`InvalidEvent`]: Error::InvalidEvent
+/// [`IncompatibleSchema`]: Error::IncompatibleSchema
+/// [`DuplicateEvent`]: Error::DuplicateEvent
+/// [`UnexpectedEvent`]: Error::UnexpectedEvent
+/// [`NotInitialized`]: Error::NotInitialized
+/// [`AlreadyInitialized`]: Error::AlreadyInitialized
+/// [`Serialization`]: Error::Serialization
+/// [`InvalidNamedKey`]: Error::InvalidNamedKey
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Error {
+    /// The event is not registered in the [`Schemas`](crate::Schemas).
+    UnknownEvent = 64_000,
+    /// The event doesn't match its [`Schema`](crate::Schema).
+    InvalidEvent = 64_001,
+    /// The event is already registered with a different [`Schema`](crate::Schema).
+    IncompatibleSchema = 64_002,
+    /// An event with the same name is already registered.
+    DuplicateEvent = 64_003,
+    /// The event is not of the expected type.
+    UnexpectedEvent = 64_004,
+    /// The events-related named keys don't exist.
+    NotInitialized = 64_005,
+    /// The events-related named keys already exist.
+    AlreadyInitialized = 64_006,
+    /// The event or the value of a named key can't be serialized.
+    Serialization = 64_007,
+    /// An events-related named key has an unexpected type or value.
+    InvalidNamedKey = 64_008,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::UnknownEvent => "Unknown event",
+            Error::InvalidEvent => "Event doesn't match its schema",
+            Error::IncompatibleSchema => "Event is already registered with a different schema",
+            Error::DuplicateEvent => "Event is already registered",
+            Error::UnexpectedEvent => "Unexpected event",
+            Error::NotInitialized => "Events are not initialized",
+            Error::AlreadyInitialized => "Events are already initialized",
+            Error::Serialization => "Serialization failed",
+            Error::InvalidNamedKey => "Invalid events named key",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,154 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use casper_types::{bytesrepr::Bytes, Key, PublicKey, URef, U128, U256, U512};
+use core::marker::PhantomData;
+
+/// Compile-time information if the [`CLType`](casper_types::CLType) of a type
+/// contains [`CLType::Any`](casper_types::CLType::Any).
+///
+/// It is derived by [`Event`](crate::Event) with `Any = ContainsAny`, so events
+/// nested in fields of other events fail the build, including `cargo check`.
+/// Fields of types that don't implement it are checked at runtime
+/// by [`validate_type`](crate::validate_type).
+pub trait HasAny {
+    /// [`ContainsAny`] if the `CLType` contains `CLType::Any`, [`NoAny`] otherwise.
+    type Any: AnyFlag;
+    /// `true` if the `CLType` contains `CLType::Any`.
+    const HAS_ANY: bool = <Self::Any as AnyFlag>::VALUE;
+}
+
+/// Type-level `bool` of [`HasAny`], either [`ContainsAny`] or [`NoAny`].
+pub trait AnyFlag {
+    /// The `bool` value of the flag.
+    const VALUE: bool;
+    /// [`ContainsAny`] if either of the flags is [`ContainsAny`].
+    type Or<F: AnyFlag>: AnyFlag;
+}
+
+/// The `CLType` contains `CLType::Any`.
+pub struct ContainsAny;
+
+/// The `CLType` doesn't contain `CLType::Any`.
+pub struct NoAny;
+
+impl AnyFlag for ContainsAny {
+    const VALUE: bool = true;
+    type Or<F: AnyFlag> = ContainsAny;
+}
+
+impl AnyFlag for NoAny {
+    const VALUE: bool = false;
+    type Or<F: AnyFlag> = F;
+}
+
+macro_rules! impl_has_no_any {
+    ($($ty:ty),*) => {
+        $(impl HasAny for $ty {
+            type Any = NoAny;
+        })*
+    };
+}
+
+impl_has_no_any!(
+    bool,
+    i32,
+    i64,
+    u8,
+    u32,
+    u64,
+    U128,
+    U256,
+    U512,
+    (),
+    String,
+    &str,
+    Key,
+    URef,
+    PublicKey,
+    Bytes
+);
+
+impl<const N: usize> HasAny for [u8; N] {
+    type Any = NoAny;
+}
+
+impl<T: HasAny> HasAny for Option<T> {
+    type Any = T::Any;
+}
+
+impl<T: HasAny> HasAny for Vec<T> {
+    type Any = T::Any;
+}
+
+impl<T: HasAny, E: HasAny> HasAny for Result<T, E> {
+    type Any = <T::Any as AnyFlag>::Or<E::Any>;
+}
+
+impl<K: HasAny, V: HasAny> HasAny for BTreeMap<K, V> {
+    type Any = <K::Any as AnyFlag>::Or<V::Any>;
+}
+
+impl<T1: HasAny> HasAny for (T1,) {
+    type Any = T1::Any;
+}
+
+impl<T1: HasAny, T2: HasAny> HasAny for (T1, T2) {
+    type Any = <T1::Any as AnyFlag>::Or<T2::Any>;
+}
+
+impl<T1: HasAny, T2: HasAny, T3: HasAny> HasAny for (T1, T2, T3) {
+    type Any = <<T1::Any as AnyFlag>::Or<T2::Any> as AnyFlag>::Or<T3::Any>;
+}
+
+/// Static check of a field type used by the derived code.
+///
+/// The method resolution picks [`KnownType`] if the type implements [`HasAny`]
+/// and falls back to [`UnknownType`], which always returns [`NoAny`], otherwise.
+#[doc(hidden)]
+pub struct AnyCheck<T>(PhantomData<T>);
+
+impl<T> AnyCheck<T> {
+    pub fn new() -> Self {
+        AnyCheck(PhantomData)
+    }
+}
+
+impl<T> Default for AnyCheck<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+pub trait KnownType {
+    type Any;
+    fn any_flag(&self) -> PhantomData<Self::Any>;
+}
+
+impl<T: HasAny> KnownType for AnyCheck<T> {
+    type Any = T::Any;
+    fn any_flag(&self) -> PhantomData<T::Any> {
+        PhantomData
+    }
+}
+
+#[doc(hidden)]
+pub trait UnknownType {
+    fn any_flag(&self) -> PhantomData<NoAny> {
+        PhantomData
+    }
+}
+
+impl<T> UnknownType for &AnyCheck<T> {}
+
+/// Fails the type check if the type implements [`HasAny`] and contains `CLType::Any`,
+/// with a mismatch between `NoAny` and `ContainsAny`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_no_any {
+    ($ty:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::{KnownType as _, UnknownType as _};
+        let _: ::core::marker::PhantomData<$crate::NoAny> =
+            (&$crate::AnyCheck::<$ty>::new()).any_flag();
+    }};
+}
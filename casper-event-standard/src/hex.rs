@@ -0,0 +1,28 @@
+//! Hex encoding of the JSON values and the node event stream.
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// Formats bytes as lowercase hex.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+/// Parses hex of either case, `None` if a digit is invalid or the length is odd.
+pub(crate) fn parse_hex(input: &str) -> Option<Vec<u8>> {
+    input
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => Some((hex_digit(*high)? << 4) | hex_digit(*low)?),
+            _ => None,
+        })
+        .collect()
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
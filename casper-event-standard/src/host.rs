@@ -0,0 +1,106 @@
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    ApiError, CLTyped,
+};
+
+use crate::error::Error;
+
+/// Storage primitives events are built on.
+///
+/// It is implemented by [`WasmHost`](crate::wasm_host::WasmHost) in the contract,
+/// by [`InMemoryHost`](crate::in_memory::InMemoryHost) in native tests
+/// and by [`NoHost`] on the other targets.
+pub(crate) trait Host {
+    /// A named key resolved by [`Host::get_key`], so it can be read and written
+    /// without looking it up again.
+    type Key;
+
+    /// Checks if the named key exists.
+    fn has_key(&self, name: &str) -> bool;
+
+    /// Resolves the named key, `None` if it doesn't exist.
+    fn get_key(&self, name: &str) -> Result<Option<Self::Key>, Error>;
+
+    /// Creates a new dictionary under the named key.
+    fn new_dictionary(&mut self, name: &str) -> Result<(), Error>;
+
+    /// Stores a new value under the named key.
+    fn put_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) -> Result<(), Error>;
+
+    /// Reads the value stored under the resolved key.
+    fn read_value<T: CLTyped + FromBytes>(&self, key: &Self::Key) -> Result<T, Error>;
+
+    /// Overwrites the value stored under the resolved key.
+    fn write_value<T: CLTyped + ToBytes>(&mut self, key: &Self::Key, value: T)
+        -> Result<(), Error>;
+
+    /// Puts an item into the dictionary stored under the resolved key.
+    fn dictionary_put(
+        &mut self,
+        dictionary: &Self::Key,
+        item_key: &str,
+        value: Bytes,
+    ) -> Result<(), Error>;
+
+    /// Stops the execution with the given error.
+    fn revert(&self, error: ApiError) -> !;
+}
+
+/// Runs `f` with the host of the current target.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn with_host<R>(f: impl FnOnce(&mut crate::wasm_host::WasmHost) -> R) -> R {
+    f(&mut crate::wasm_host::WasmHost)
+}
+
+/// Runs `f` with the host of the current thread.
+#[cfg(all(not(target_arch = "wasm32"), feature = "test-support"))]
+pub(crate) fn with_host<R>(f: impl FnOnce(&mut crate::in_memory::InMemoryHost) -> R) -> R {
+    crate::in_memory::with_host(f)
+}
+
+/// Host of the targets without storage, it can't be constructed.
+#[cfg(not(any(target_arch = "wasm32", feature = "test-support")))]
+pub(crate) enum NoHost {}
+
+#[cfg(not(any(target_arch = "wasm32", feature = "test-support")))]
+impl Host for NoHost {
+    type Key = ();
+
+    fn has_key(&self, _name: &str) -> bool {
+        match *self {}
+    }
+
+    fn get_key(&self, _name: &str) -> Result<Option<()>, Error> {
+        match *self {}
+    }
+
+    fn new_dictionary(&mut self, _name: &str) -> Result<(), Error> {
+        match *self {}
+    }
+
+    fn put_value<T: CLTyped + ToBytes>(&mut self, _name: &str, _value: T) -> Result<(), Error> {
+        match *self {}
+    }
+
+    fn read_value<T: CLTyped + FromBytes>(&self, _key: &()) -> Result<T, Error> {
+        match *self {}
+    }
+
+    fn write_value<T: CLTyped + ToBytes>(&mut self, _key: &(), _value: T) -> Result<(), Error> {
+        match *self {}
+    }
+
+    fn dictionary_put(&mut self, _dictionary: &(), _key: &str, _value: Bytes) -> Result<(), Error> {
+        match *self {}
+    }
+
+    fn revert(&self, _error: ApiError) -> ! {
+        match *self {}
+    }
+}
+
+/// Panics, the events can't be stored on the current target.
+#[cfg(not(any(target_arch = "wasm32", feature = "test-support")))]
+pub(crate) fn with_host<R>(_f: impl FnOnce(&mut NoHost) -> R) -> R {
+    panic!("Events can be used only in wasm32 or with test-support feature.")
+}
@@ -0,0 +1,149 @@
+//! In-memory replacement of the contract storage for native tests.
+//!
+//! On non-wasm targets [`init`](crate::init) and [`emit`](crate::emit) write
+//! to a storage local to the current thread, so the contract code can be
+//! tested with `cargo test`. Reverts become panics.
+//!
+//! ```rust
+//! casper_event_standard::init(Schemas::new().with::<Transfer>());
+//! casper_event_standard::emit(transfer);
+//! assert_eq!(in_memory::emitted_events().len(), 1);
+//! in_memory::clear();
+//! ```
+use alloc::{collections::BTreeMap, string::String, string::ToString, vec::Vec};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    ApiError, CLTyped,
+};
+use std::{cell::RefCell, thread_local};
+
+use crate::{
+    contract::{read_length, read_schemas},
+    error::Error,
+    host::Host,
+    keys::EventKeys,
+    schema::Schemas,
+};
+
+thread_local! {
+    static HOST: RefCell<InMemoryHost> = RefCell::new(InMemoryHost::default());
+}
+
+/// [`Host`] that keeps named keys and dictionaries in memory.
+#[derive(Default)]
+pub(crate) struct InMemoryHost {
+    values: BTreeMap<String, Vec<u8>>,
+    dictionaries: BTreeMap<String, BTreeMap<String, Bytes>>,
+}
+
+impl Host for InMemoryHost {
+    type Key = String;
+
+    fn has_key(&self, name: &str) -> bool {
+        self.values.contains_key(name) || self.dictionaries.contains_key(name)
+    }
+
+    fn get_key(&self, name: &str) -> Result<Option<String>, Error> {
+        Ok(self.has_key(name).then(|| name.to_string()))
+    }
+
+    fn new_dictionary(&mut self, name: &str) -> Result<(), Error> {
+        if self.has_key(name) {
+            return Err(Error::InvalidNamedKey);
+        }
+        self.dictionaries.insert(name.to_string(), BTreeMap::new());
+        Ok(())
+    }
+
+    fn put_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) -> Result<(), Error> {
+        let bytes = value.to_bytes().map_err(|_| Error::Serialization)?;
+        self.values.insert(name.to_string(), bytes);
+        Ok(())
+    }
+
+    fn read_value<T: CLTyped + FromBytes>(&self, name: &String) -> Result<T, Error> {
+        let bytes = self.values.get(name).ok_or(Error::InvalidNamedKey)?;
+        let (value, _) = T::from_bytes(bytes).map_err(|_| Error::InvalidNamedKey)?;
+        Ok(value)
+    }
+
+    fn write_value<T: CLTyped + ToBytes>(&mut self, name: &String, value: T) -> Result<(), Error> {
+        if !self.values.contains_key(name) {
+            return Err(Error::InvalidNamedKey);
+        }
+        self.put_value(name, value)
+    }
+
+    fn dictionary_put(
+        &mut self,
+        dictionary: &String,
+        key: &str,
+        value: Bytes,
+    ) -> Result<(), Error> {
+        let items = self
+            .dictionaries
+            .get_mut(dictionary)
+            .ok_or(Error::InvalidNamedKey)?;
+        items.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    fn revert(&self, error: ApiError) -> ! {
+        panic!("Reverted with {:?}", error)
+    }
+}
+
+pub(crate) fn with_host<R>(f: impl FnOnce(&mut InMemoryHost) -> R) -> R {
+    HOST.with(|host| f(&mut host.borrow_mut()))
+}
+
+/// Returns the events emitted in the current thread, in the emission order.
+pub fn emitted_events() -> Vec<Bytes> {
+    emitted_events_with_keys(&EventKeys::default())
+}
+
+/// Returns the events emitted in the current thread to the event stream
+/// identified by `prefix`, in the emission order.
+pub fn emitted_events_with_prefix(prefix: &str) -> Vec<Bytes> {
+    emitted_events_with_keys(&EventKeys::with_prefix(prefix))
+}
+
+/// Returns the [`Schemas`] registered in the current thread.
+pub fn schemas() -> Option<Schemas> {
+    with_host(|host| read_schemas(host, &EventKeys::default()).ok())
+}
+
+/// Returns the [`Schemas`] of the event stream identified by `prefix`
+/// registered in the current thread.
+pub fn schemas_with_prefix(prefix: &str) -> Option<Schemas> {
+    with_host(|host| read_schemas(host, &EventKeys::with_prefix(prefix)).ok())
+}
+
+/// Returns the names of the named keys created in the current thread, sorted.
+pub fn named_keys() -> Vec<String> {
+    with_host(|host| {
+        let mut names: Vec<String> = host
+            .values
+            .keys()
+            .chain(host.dictionaries.keys())
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    })
+}
+
+fn emitted_events_with_keys(keys: &EventKeys) -> Vec<Bytes> {
+    with_host(|host| {
+        let length = read_length(host, keys).unwrap_or_default();
+        let items = host.dictionaries.get(keys.events_dict());
+        (0..length)
+            .filter_map(|index| items?.get(&index.to_string()).cloned())
+            .collect()
+    })
+}
+
+/// Removes all the events-related data of the current thread.
+pub fn clear() {
+    with_host(|host| *host = InMemoryHost::default());
+}
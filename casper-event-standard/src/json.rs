@@ -0,0 +1,109 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use casper_types::{bytesrepr::ToBytes, CLType};
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::{
+    decoder::{decode_event, DecodeError, DecodedEvent, Value},
+    hex::to_hex,
+    schema::{Schema, Schemas},
+};
+
+/// Decodes an event and renders it as a JSON object.
+///
+/// See [`event_to_json`] for the format.
+pub fn decode_event_to_json(schemas: &Schemas, bytes: &[u8]) -> Result<JsonValue, DecodeError> {
+    let event = decode_event(schemas, bytes)?;
+    let schema = &schemas.0[event.name()];
+    Ok(event_to_json(schema, &event))
+}
+
+/// Renders an event as `{"name": ..., "fields": {...}}`.
+///
+/// The fields follow the order of the `schema`.
+/// See [`value_to_json`] for the encoding of the values.
+pub fn event_to_json(schema: &Schema, event: &DecodedEvent) -> JsonValue {
+    let fields = schema
+        .elems()
+        .iter()
+        .map(|(name, ty)| {
+            let value = event
+                .field(name)
+                .map(|value| value_to_json(&ty.0, value))
+                .unwrap_or(JsonValue::Null);
+            (name.clone(), value)
+        })
+        .collect::<Map<String, JsonValue>>();
+    json!({
+        "name": event.name(),
+        "fields": fields,
+    })
+}
+
+/// Renders a value of the given [`CLType`] as JSON.
+///
+/// * `U128`, `U256` and `U512` are decimal strings,
+/// * `Key` and `URef` are formatted strings, e.g. `hash-...`,
+/// * `PublicKey` and `ByteArray` are hex strings,
+/// * `Option` is `null` or the inner value,
+/// * `Result` is `{"Ok": ...}` or `{"Err": ...}`,
+/// * `Map` is an object if the keys are `String`s, an array of `[key, value]` pairs otherwise,
+/// * `List` and tuples are arrays,
+/// * `Unit` is `null`.
+///
+/// A value that doesn't match the type is rendered as `null`.
+pub fn value_to_json(ty: &CLType, value: &Value) -> JsonValue {
+    match (ty, value) {
+        (_, Value::Bool(v)) => json!(v),
+        (_, Value::I32(v)) => json!(v),
+        (_, Value::I64(v)) => json!(v),
+        (_, Value::U8(v)) => json!(v),
+        (_, Value::U32(v)) => json!(v),
+        (_, Value::U64(v)) => json!(v),
+        (_, Value::U128(v)) => json!(v.to_string()),
+        (_, Value::U256(v)) => json!(v.to_string()),
+        (_, Value::U512(v)) => json!(v.to_string()),
+        (_, Value::Unit) => JsonValue::Null,
+        (_, Value::String(v)) => json!(v),
+        (_, Value::Key(v)) => json!(v.to_formatted_string()),
+        (_, Value::URef(v)) => json!(v.to_formatted_string()),
+        (_, Value::PublicKey(v)) => json!(to_hex(&v.to_bytes().unwrap_or_default())),
+        (_, Value::ByteArray(v)) => json!(to_hex(v)),
+        (_, Value::Option(None)) => JsonValue::Null,
+        (CLType::Option(ty), Value::Option(Some(v))) => value_to_json(ty, v),
+        (CLType::List(ty), Value::List(values)) => values
+            .iter()
+            .map(|value| value_to_json(ty, value))
+            .collect(),
+        (CLType::Result { ok, .. }, Value::Result(Ok(v))) => json!({ "Ok": value_to_json(ok, v) }),
+        (CLType::Result { err, .. }, Value::Result(Err(v))) => {
+            json!({ "Err": value_to_json(err, v) })
+        }
+        (CLType::Map { key, value }, Value::Map(entries)) if **key == CLType::String => entries
+            .iter()
+            .filter_map(|(k, v)| match k {
+                Value::String(k) => Some((k.clone(), value_to_json(value, v))),
+                _ => None,
+            })
+            .collect::<Map<String, JsonValue>>()
+            .into(),
+        (CLType::Map { key, value }, Value::Map(entries)) => entries
+            .iter()
+            .map(|(k, v)| json!([value_to_json(key, k), value_to_json(value, v)]))
+            .collect(),
+        (CLType::Tuple1(types), Value::Tuple(values)) => tuple_to_json(types, values),
+        (CLType::Tuple2(types), Value::Tuple(values)) => tuple_to_json(types, values),
+        (CLType::Tuple3(types), Value::Tuple(values)) => tuple_to_json(types, values),
+        _ => JsonValue::Null,
+    }
+}
+
+fn tuple_to_json(types: &[Box<CLType>], values: &[Value]) -> JsonValue {
+    types
+        .iter()
+        .zip(values)
+        .map(|(ty, value)| value_to_json(ty, value))
+        .collect()
+}
@@ -0,0 +1,141 @@
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use casper_types::CLType;
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::schema::{Schema, Schemas};
+
+/// The dialect of the generated JSON Schema documents.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const DECIMAL_PATTERN: &str = "^(0|[1-9][0-9]*)$";
+const KEY_PATTERN: &str = "^((account-hash|hash|transfer|deploy|balance|bid|withdraw|dictionary|system-contract-registry|era-summary|unbond|chainspec-registry|checksum-registry)-[0-9a-f]{64}|uref-[0-9a-f]{64}-[0-7]{3}|era-[0-9]+)$";
+const UREF_PATTERN: &str = "^uref-[0-9a-f]{64}-[0-7]{3}$";
+const PUBLIC_KEY_PATTERN: &str = "^(00|01[0-9a-f]{64}|02[0-9a-f]{66})$";
+
+/// Builds a JSON Schema document of every event in `schemas`, keyed by the event name.
+pub fn schemas_to_json_schemas(schemas: &Schemas) -> Vec<(String, JsonValue)> {
+    schemas
+        .0
+        .iter()
+        .map(|(name, schema)| (name.clone(), schema_to_json_schema(name, schema)))
+        .collect()
+}
+
+/// Builds a JSON Schema (draft 2020-12) document of the event `name`.
+///
+/// The document describes the output of [`event_to_json`](crate::event_to_json):
+/// an object with the `name` and the `fields` object, which requires
+/// all the fields of the `schema` in its order.
+pub fn schema_to_json_schema(name: &str, schema: &Schema) -> JsonValue {
+    let properties = schema
+        .elems()
+        .iter()
+        .map(|(name, ty)| (name.clone(), cl_type_to_json_schema(&ty.0)))
+        .collect::<Map<String, JsonValue>>();
+    let required = schema
+        .elems()
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "title": name,
+        "type": "object",
+        "properties": {
+            "name": { "const": name },
+            "fields": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            },
+        },
+        "required": ["name", "fields"],
+        "additionalProperties": false,
+    })
+}
+
+/// Builds a JSON Schema of a value of the given [`CLType`], as rendered
+/// by [`value_to_json`](crate::value_to_json).
+///
+/// * Integers up to `U64` are bounded `integer`s,
+/// * `U128`, `U256` and `U512` are decimal strings,
+/// * `Key` and `URef` are strings constrained by the formatted key pattern,
+/// * `PublicKey` and `ByteArray` are fixed-length hex strings,
+/// * `Option` is nullable,
+/// * `Map` is an object if the keys are `String`s, an array of `[key, value]` pairs otherwise,
+/// * `List` is an array and tuples are fixed-length arrays,
+/// * `Any` accepts any value.
+pub fn cl_type_to_json_schema(ty: &CLType) -> JsonValue {
+    match ty {
+        CLType::Bool => json!({ "type": "boolean" }),
+        CLType::I32 => integer(i32::MIN, i32::MAX),
+        CLType::I64 => integer(i64::MIN, i64::MAX),
+        CLType::U8 => integer(u8::MIN, u8::MAX),
+        CLType::U32 => integer(u32::MIN, u32::MAX),
+        CLType::U64 => integer(u64::MIN, u64::MAX),
+        CLType::U128 | CLType::U256 | CLType::U512 => string(DECIMAL_PATTERN),
+        CLType::Unit => json!({ "type": "null" }),
+        CLType::String => json!({ "type": "string" }),
+        CLType::Key => string(KEY_PATTERN),
+        CLType::URef => string(UREF_PATTERN),
+        CLType::PublicKey => string(PUBLIC_KEY_PATTERN),
+        CLType::ByteArray(len) => string(&format!("^[0-9a-f]{{{}}}$", 2 * len)),
+        CLType::Option(ty) => json!({
+            "anyOf": [cl_type_to_json_schema(ty), { "type": "null" }],
+        }),
+        CLType::List(ty) => json!({
+            "type": "array",
+            "items": cl_type_to_json_schema(ty),
+        }),
+        CLType::Result { ok, err } => json!({
+            "oneOf": [variant("Ok", ok), variant("Err", err)],
+        }),
+        CLType::Map { key, value } if **key == CLType::String => json!({
+            "type": "object",
+            "additionalProperties": cl_type_to_json_schema(value),
+        }),
+        CLType::Map { key, value } => json!({
+            "type": "array",
+            "items": tuple(&[key.clone(), value.clone()]),
+        }),
+        CLType::Tuple1(types) => tuple(types),
+        CLType::Tuple2(types) => tuple(types),
+        CLType::Tuple3(types) => tuple(types),
+        CLType::Any => json!({}),
+    }
+}
+
+fn integer<T: Into<JsonValue>>(min: T, max: T) -> JsonValue {
+    json!({
+        "type": "integer",
+        "minimum": min.into(),
+        "maximum": max.into(),
+    })
+}
+
+fn string(pattern: &str) -> JsonValue {
+    json!({ "type": "string", "pattern": pattern })
+}
+
+fn variant(name: &str, ty: &CLType) -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": { name: cl_type_to_json_schema(ty) },
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+fn tuple(types: &[Box<CLType>]) -> JsonValue {
+    let items = types
+        .iter()
+        .map(|ty| cl_type_to_json_schema(ty))
+        .collect::<Vec<_>>();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": types.len(),
+    })
+}
@@ -0,0 +1,66 @@
+use alloc::{format, string::String};
+
+use crate::{CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH, EVENTS_METADATA, EVENTS_SCHEMA};
+
+/// The named keys of a single event stream.
+///
+/// A contract can have multiple independent event streams,
+/// each with its own dictionary, counter, schemas and version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventKeys {
+    events_dict: String,
+    events_length: String,
+    events_schema: String,
+    ces_version: String,
+    events_metadata: String,
+}
+
+impl EventKeys {
+    /// Creates the keys of the stream identified by `prefix`,
+    /// e.g. `staking` gives `staking__events`, `staking__events_length`, ...
+    ///
+    /// The empty prefix gives the keys of the default stream.
+    pub fn with_prefix(prefix: &str) -> Self {
+        EventKeys {
+            events_dict: format!("{prefix}{EVENTS_DICT}"),
+            events_length: format!("{prefix}{EVENTS_LENGTH}"),
+            events_schema: format!("{prefix}{EVENTS_SCHEMA}"),
+            ces_version: format!("{prefix}{CES_VERSION_KEY}"),
+            events_metadata: format!("{prefix}{EVENTS_METADATA}"),
+        }
+    }
+
+    /// The key under which the events are stored.
+    pub fn events_dict(&self) -> &str {
+        &self.events_dict
+    }
+
+    /// The key under which the events length is stored.
+    pub fn events_length(&self) -> &str {
+        &self.events_length
+    }
+
+    /// The key under which the event schemas are stored.
+    pub fn events_schema(&self) -> &str {
+        &self.events_schema
+    }
+
+    /// The key under which the ces version is stored.
+    pub fn ces_version(&self) -> &str {
+        &self.ces_version
+    }
+
+    /// The key under which the [`EventsMetadata`](crate::EventsMetadata) is stored
+    /// in the [`Layout::Metadata`](crate::Layout::Metadata).
+    pub fn events_metadata(&self) -> &str {
+        &self.events_metadata
+    }
+}
+
+impl Default for EventKeys {
+    /// The keys of the default stream: [`EVENTS_DICT`], [`EVENTS_LENGTH`],
+    /// [`EVENTS_SCHEMA`], [`CES_VERSION_KEY`] and [`EVENTS_METADATA`].
+    fn default() -> Self {
+        Self::with_prefix("")
+    }
+}
@@ -29,40 +29,144 @@
 #[doc(hidden)]
 pub extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[doc(hidden)]
 pub use casper_types;
 
-use alloc::string::String;
-use casper_types::{bytesrepr, CLType, CLTyped};
+use alloc::{string::String, vec, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, CLTyped,
+};
 
-/// Macro that derives [`CLTyped`], [`FromBytes`], [`ToBytes`] and [`EventInstance`].
+/// Macro that derives [`CLTyped`], [`FromBytes`], [`ToBytes`], [`EventInstance`]
+/// and [`FromBytesLenient`].
+///
+/// The derived [`FromBytes`] fails with [`bytesrepr::Error::Formatting`]
+/// if the serialized name is not the name of the event.
+///
+/// Unit structs have an empty schema. Unnamed fields of tuple structs are named
+/// `0`, `1`, ... in the schema, unless renamed.
+///
+/// When used on an enum, every variant becomes a separate event named after
+/// the variant and [`EventInstances`] is derived instead of [`EventInstance`].
+///
+/// Fields can't have [`CLType::Any`](casper_types::CLType::Any) in their `CLType`,
+/// e.g. when nesting an event in another event. For field types implementing
+/// [`HasAny`] this is a type error reported by `cargo check`, for others [`ToBytes`]
+/// fails at runtime.
+///
+/// Generic events require every type parameter to implement [`CLTyped`],
+/// [`ToBytes`] and [`FromBytes`]. All the instantiations share the event name,
+/// while the schema uses the `CLType`s of the concrete type arguments,
+/// so only one instantiation can be registered in [`Schemas`].
+///
+/// The event name can be overridden with `#[event(name = "Name")]` on a struct
+/// or an enum variant. The serialized name is then `event_Name`.
+/// The [`EVENT_PREFIX`] itself can't be changed. It is fixed by the Casper Event Standard,
+/// and decoders, [`Schemas::validate`] and the generated TypeScript rely on it
+/// to tell events apart from other dictionary values.
+///
+/// Fields accept `#[event(...)]` attributes:
+/// * `rename = "name"` - sets the name of the schema element,
+/// * `skip` - the field is not emitted and is set to `Default::default()` when decoded,
+/// * `cl_type = Type` - the schema element uses `<Type as CLTyped>::cl_type()`,
+///   e.g. for newtype wrappers that don't implement [`CLTyped`].
 ///
 /// [`CLTyped`]: casper_types::CLTyped
-/// [`FromBytes`]: casper_types::bytesrepr::FromBytes
 /// [`ToBytes`]: casper_types::bytesrepr::ToBytes
 pub use casper_event_standard_macro::Event;
 
+/// Macro that derives [`CLTyped`], [`FromBytes`], [`ToBytes`] and [`HasAny`]
+/// for a struct used as a field of an event.
+///
+/// The struct is represented as a tuple of its fields, so its `CLType` is not
+/// [`CLType::Any`](casper_types::CLType::Any) and decoders can interpret it.
+/// Structs with more than three fields are nested tuples, e.g. `(a, b, (c, d))`,
+/// that serialize to the fields' bytes one after another.
+///
+/// The names of the fields are not recorded, the [`Schema`] of the event only has
+/// the tuple `CLType`. Decoded values, JSON and TypeScript types are tuples too.
+///
+/// [`HasAny`] of a generic struct follows its generic fields, so `Meta<T>` nesting
+/// an event, e.g. `Meta<Transfer>`, fails the type check of the event using it.
+///
+/// Fields accept `#[event(skip)]` and `#[event(cl_type = ...)]` as in [`Event`].
+/// `rename` and the `name` of the struct are rejected, as there is no name to change.
+///
+/// [`ToBytes`]: casper_types::bytesrepr::ToBytes
+pub use casper_event_standard_macro::EventField;
+
+/// Macro that derives a registry of events for an enum whose variants
+/// wrap types deriving [`Event`], e.g. `Transfer(Transfer)`.
+///
+/// It derives [`EventInstances`] with the schemas of all the variants and adds:
+/// * `fn from_event_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error>`,
+///   which picks the variant based on the serialized name,
+/// * `fn all_schemas() -> Schemas`, which builds [`Schemas`] of all the variants.
+pub use casper_event_standard_macro::EventSet;
+
 mod cl_type2;
+#[cfg(feature = "std")]
+mod decoder;
+mod diff;
+mod error;
+mod has_any;
+#[cfg(feature = "json")]
+mod hex;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+mod json_schema;
+mod keys;
+mod metadata;
+mod notation;
 mod schema;
+#[cfg(feature = "json")]
+mod sse;
+#[cfg(feature = "typescript")]
+mod typescript;
 
 pub use cl_type2::CLType2;
+#[cfg(feature = "std")]
+pub use decoder::{decode_event, decode_value, DecodeError, DecodedEvent, Value};
+pub use diff::{Compatibility, FieldChange, SchemasDiff};
+pub use error::Error;
+#[doc(hidden)]
+pub use has_any::{AnyCheck, KnownType, UnknownType};
+pub use has_any::{AnyFlag, ContainsAny, HasAny, NoAny};
+#[cfg(feature = "json")]
+pub use json::{decode_event_to_json, event_to_json, value_to_json};
+#[cfg(feature = "json")]
+pub use json_schema::{
+    cl_type_to_json_schema, schema_to_json_schema, schemas_to_json_schemas, JSON_SCHEMA_DIALECT,
+};
+pub use keys::EventKeys;
+pub use metadata::{EventsMetadata, Layout, METADATA_VERSION};
+pub use notation::ParseTypeError;
 pub use schema::{Schema, Schemas};
+#[cfg(feature = "json")]
+pub use sse::{events_from_message, events_from_sse, SseError, SseEvent};
+#[cfg(feature = "typescript")]
+pub use typescript::schemas_to_typescript;
 
-#[cfg(target_arch = "wasm32")]
 mod contract;
-
+mod host;
+#[cfg(all(not(target_arch = "wasm32"), feature = "test-support"))]
+pub mod in_memory;
 #[cfg(target_arch = "wasm32")]
-pub use contract::{emit, init};
+mod wasm_host;
 
-#[cfg(not(target_arch = "wasm32"))]
-pub fn init(_schemas: Schemas) {
-    panic!("Init can be used only in wasm32.")
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-pub fn emit<T>(_event: T) {
-    panic!("Emit can be used only in wasm32.")
-}
+pub use contract::{
+    emit, emit_batch, emit_batch_to, emit_checked, emit_checked_to, emit_or_init, emit_or_init_to,
+    emit_to, init, init_with_layout, init_with_prefix, register_schemas,
+    register_schemas_with_prefix, try_emit, try_emit_batch, try_emit_batch_to, try_emit_checked,
+    try_emit_checked_to, try_emit_or_init, try_emit_or_init_to, try_emit_to, try_init,
+    try_init_with_layout, try_init_with_prefix, try_register_schemas,
+    try_register_schemas_with_prefix,
+};
 
 /// The key under which the events are stored.
 pub const EVENTS_DICT: &str = "__events";
@@ -72,6 +176,10 @@ pub const EVENTS_LENGTH: &str = "__events_length";
 pub const EVENTS_SCHEMA: &str = "__events_schema";
 /// The key under which the ces version is stored.
 pub const CES_VERSION_KEY: &str = "__events_ces_version";
+/// The key under which the events metadata is stored in the [`Layout::Metadata`].
+pub const EVENTS_METADATA: &str = "__events_metadata";
+/// The prefix of the serialized event name, fixed by the Casper Event Standard.
+pub const EVENT_PREFIX: &str = "event_";
 /// The version of CES implemented in this library.
 pub const CES_VERSION: &str = "1.1";
 
@@ -83,15 +191,85 @@ pub trait EventInstance {
     fn schema() -> schema::Schema;
 }
 
+/// Helper trait, used for the schema generation of types describing
+/// one or more events.
+///
+/// It is implemented for every [`EventInstance`] and derived for enums.
+pub trait EventInstances {
+    /// Returns the names and [`Schema`](schema::Schema)s of all the events.
+    fn schemas() -> Vec<(String, schema::Schema)>;
+    /// Returns the names of all the events, without building their schemas.
+    fn names() -> Vec<String>;
+}
+
+impl<T: EventInstance> EventInstances for T {
+    fn schemas() -> Vec<(String, schema::Schema)> {
+        vec![(T::name(), T::schema())]
+    }
+
+    fn names() -> Vec<String> {
+        vec![T::name()]
+    }
+}
+
+/// Deserialization of an event that doesn't check the event name.
+///
+/// Derived for structs by [`Event`].
+pub trait FromBytesLenient: Sized {
+    /// Deserializes the event, skipping the leading name whatever it is.
+    fn from_bytes_lenient(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error>;
+}
+
 /// Extracts full name of the event including `event_` prefix.
 pub fn try_full_name_from_bytes(bytes: &[u8]) -> Result<String, bytesrepr::Error> {
-    let (name, _) = bytesrepr::FromBytes::from_bytes(bytes)?;
-    Ok(name)
+    let (name, _) = split_full_name(bytes)?;
+    Ok(String::from(name))
+}
+
+/// Splits the bytes of an event into its full name, including `event_` prefix,
+/// and the bytes of its fields. The name is borrowed from `bytes`.
+pub fn split_full_name(bytes: &[u8]) -> Result<(&str, &[u8]), bytesrepr::Error> {
+    let (len, bytes) = u32::from_bytes(bytes)?;
+    if bytes.len() < len as usize {
+        return Err(bytesrepr::Error::EarlyEndOfStream);
+    }
+    let (name, bytes) = bytes.split_at(len as usize);
+    let name = core::str::from_utf8(name).map_err(|_| bytesrepr::Error::Formatting)?;
+    Ok((name, bytes))
+}
+
+/// Extracts the name of the event without `event_` prefix, borrowed from `bytes`.
+///
+/// Fails with [`Error::InvalidEvent`] if the name can't be deserialized
+/// and with [`Error::UnexpectedEvent`] if it doesn't start with `event_`.
+pub fn event_name_from_bytes(bytes: &[u8]) -> Result<&str, Error> {
+    let (full_name, _) = split_full_name(bytes).map_err(|_| Error::InvalidEvent)?;
+    full_name
+        .strip_prefix(EVENT_PREFIX)
+        .ok_or(Error::UnexpectedEvent)
+}
+
+/// Deserializes an event of type `T`.
+///
+/// Fails with [`Error::UnexpectedEvent`] if the serialized name is not
+/// one of the names of `T`, and with [`Error::InvalidEvent`]
+/// if the bytes can't be deserialized.
+pub fn from_event_bytes<T: FromBytes + EventInstances>(bytes: &[u8]) -> Result<(T, &[u8]), Error> {
+    let name = event_name_from_bytes(bytes)?;
+    if !T::names().iter().any(|expected| expected == name) {
+        return Err(Error::UnexpectedEvent);
+    }
+    T::from_bytes(bytes).map_err(|_| Error::InvalidEvent)
 }
 
 /// Make sure the type of a value is not [`CLType::Any`](casper_types::CLType::Any).
 pub fn validate_type<T: CLTyped>(_: &T) -> Result<(), bytesrepr::Error> {
-    if has_any(&T::cl_type()) {
+    validate_cl_type(&T::cl_type())
+}
+
+/// Make sure the type is not [`CLType::Any`](casper_types::CLType::Any).
+pub fn validate_cl_type(ty: &CLType) -> Result<(), bytesrepr::Error> {
+    if has_any(ty) {
         Err(bytesrepr::Error::Formatting)
     } else {
         Ok(())
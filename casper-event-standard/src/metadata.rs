@@ -0,0 +1,111 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue,
+};
+
+use crate::{error::Error, keys::EventKeys, schema::Schemas};
+
+/// The version tag of the [`EventsMetadata`] encoding.
+pub const METADATA_VERSION: u8 = 1;
+
+/// How the events-related named keys are stored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// The counter, schemas and CES version are stored under separate named keys:
+    /// [`EVENTS_LENGTH`](crate::EVENTS_LENGTH), [`EVENTS_SCHEMA`](crate::EVENTS_SCHEMA)
+    /// and [`CES_VERSION_KEY`](crate::CES_VERSION_KEY).
+    #[default]
+    NamedKeys,
+    /// The counter, schemas and CES version are stored together as [`EventsMetadata`]
+    /// under [`EVENTS_METADATA`](crate::EVENTS_METADATA).
+    ///
+    /// It needs two named keys instead of four, but every emit reads and
+    /// writes the whole metadata, including the schemas.
+    Metadata,
+}
+
+/// The events counter, schemas and CES version of an event stream.
+///
+/// In the [`Layout::Metadata`] it is stored under a single named key,
+/// encoded as `(METADATA_VERSION, (ces_version, events_length, schemas))`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EventsMetadata {
+    /// The version of CES the events are emitted with.
+    pub ces_version: String,
+    /// The number of emitted events.
+    pub events_length: u32,
+    /// The schemas of the events.
+    pub schemas: Schemas,
+}
+
+impl EventsMetadata {
+    /// Reads the metadata of the event stream identified by `keys` in either [`Layout`].
+    ///
+    /// `query` returns the value stored under a named key, e.g. by querying
+    /// the global state. Fails with [`Error::NotInitialized`] if the keys
+    /// don't exist and with [`Error::InvalidNamedKey`] if a value can't be decoded.
+    pub fn read<F: FnMut(&str) -> Option<CLValue>>(
+        keys: &EventKeys,
+        mut query: F,
+    ) -> Result<Self, Error> {
+        if let Some(value) = query(keys.events_metadata()) {
+            return into_t(value);
+        }
+        let mut read = |name: &str| query(name).ok_or(Error::NotInitialized);
+        Ok(EventsMetadata {
+            ces_version: into_t(read(keys.ces_version())?)?,
+            events_length: into_t(read(keys.events_length())?)?,
+            schemas: into_t(read(keys.events_schema())?)?,
+        })
+    }
+}
+
+fn into_t<T: CLTyped + FromBytes>(value: CLValue) -> Result<T, Error> {
+    value.into_t().map_err(|_| Error::InvalidNamedKey)
+}
+
+impl CLTyped for EventsMetadata {
+    fn cl_type() -> CLType {
+        CLType::Tuple2([
+            Box::new(u8::cl_type()),
+            Box::new(<(String, u32, Schemas)>::cl_type()),
+        ])
+    }
+}
+
+impl ToBytes for EventsMetadata {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut vec = Vec::with_capacity(self.serialized_length());
+        vec.extend(METADATA_VERSION.to_bytes()?);
+        vec.extend(self.ces_version.to_bytes()?);
+        vec.extend(self.events_length.to_bytes()?);
+        vec.extend(self.schemas.to_bytes()?);
+        Ok(vec)
+    }
+
+    fn serialized_length(&self) -> usize {
+        METADATA_VERSION.serialized_length()
+            + self.ces_version.serialized_length()
+            + self.events_length.serialized_length()
+            + self.schemas.serialized_length()
+    }
+}
+
+impl FromBytes for EventsMetadata {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (version, bytes) = u8::from_bytes(bytes)?;
+        if version != METADATA_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (ces_version, bytes) = String::from_bytes(bytes)?;
+        let (events_length, bytes) = u32::from_bytes(bytes)?;
+        let (schemas, bytes) = Schemas::from_bytes(bytes)?;
+        let metadata = EventsMetadata {
+            ces_version,
+            events_length,
+            schemas,
+        };
+        Ok((metadata, bytes))
+    }
+}
@@ -0,0 +1,248 @@
+//! Human-readable notation of [`CLType2`] and [`Schema`].
+//!
+//! Types are written the way they are named in [`CLType`], with generic
+//! arguments in angle brackets, e.g. `Map<String, List<Option<U256>>>`,
+//! `Result<Unit, String>`, `Tuple3<U8, Key, PublicKey>` or `ByteArray(32)`.
+//! A schema is written as `{amount: U256, from: Key}`.
+use alloc::{boxed::Box, string::String};
+use casper_types::CLType;
+use core::{fmt, str::FromStr};
+
+use crate::{cl_type2::CLType2, schema::Schema};
+
+/// The error returned when a [`CLType2`] or a [`Schema`] can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTypeError {
+    /// The name is not a name of a [`CLType`].
+    UnknownType(String),
+    /// The input doesn't continue with the expected token.
+    Expected(&'static str),
+    /// The input continues after the parsed type.
+    TrailingInput(String),
+}
+
+impl fmt::Display for ParseTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTypeError::UnknownType(name) => write!(f, "Unknown type: {}", name),
+            ParseTypeError::Expected(token) => write!(f, "Expected {}", token),
+            ParseTypeError::TrailingInput(input) => write!(f, "Unexpected input: {}", input),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTypeError {}
+
+impl fmt::Display for CLType2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cl_type(f, &self.0)
+    }
+}
+
+impl FromStr for CLType2 {
+    type Err = ParseTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s };
+        let cl_type = parser.cl_type()?;
+        parser.end()?;
+        Ok(CLType2(cl_type))
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (index, (name, ty)) in self.elems().iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {}", name, ty)?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl FromStr for Schema {
+    type Err = ParseTypeError;
+
+    /// Parses a schema. Field names can't contain `:`, `,`, `{` and `}`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s };
+        let mut schema = Schema::new();
+        parser.expect("{")?;
+        if !parser.eat("}") {
+            loop {
+                let name = parser.field_name()?;
+                parser.expect(":")?;
+                schema.with_elem(name, parser.cl_type()?);
+                if parser.eat("}") {
+                    break;
+                }
+                parser.expect(",")?;
+            }
+        }
+        parser.end()?;
+        Ok(schema)
+    }
+}
+
+fn write_cl_type(f: &mut fmt::Formatter<'_>, cl_type: &CLType) -> fmt::Result {
+    match cl_type {
+        CLType::Bool => f.write_str("Bool"),
+        CLType::I32 => f.write_str("I32"),
+        CLType::I64 => f.write_str("I64"),
+        CLType::U8 => f.write_str("U8"),
+        CLType::U32 => f.write_str("U32"),
+        CLType::U64 => f.write_str("U64"),
+        CLType::U128 => f.write_str("U128"),
+        CLType::U256 => f.write_str("U256"),
+        CLType::U512 => f.write_str("U512"),
+        CLType::Unit => f.write_str("Unit"),
+        CLType::String => f.write_str("String"),
+        CLType::Key => f.write_str("Key"),
+        CLType::URef => f.write_str("URef"),
+        CLType::PublicKey => f.write_str("PublicKey"),
+        CLType::Any => f.write_str("Any"),
+        CLType::ByteArray(len) => write!(f, "ByteArray({})", len),
+        CLType::Option(ty) => write_generic(f, "Option", &[ty]),
+        CLType::List(ty) => write_generic(f, "List", &[ty]),
+        CLType::Result { ok, err } => write_generic(f, "Result", &[ok, err]),
+        CLType::Map { key, value } => write_generic(f, "Map", &[key, value]),
+        CLType::Tuple1([t1]) => write_generic(f, "Tuple1", &[t1]),
+        CLType::Tuple2([t1, t2]) => write_generic(f, "Tuple2", &[t1, t2]),
+        CLType::Tuple3([t1, t2, t3]) => write_generic(f, "Tuple3", &[t1, t2, t3]),
+    }
+}
+
+fn write_generic(f: &mut fmt::Formatter<'_>, name: &str, args: &[&CLType]) -> fmt::Result {
+    write!(f, "{}<", name)?;
+    for (index, arg) in args.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_cl_type(f, arg)?;
+    }
+    f.write_str(">")
+}
+
+/// Recursive descent parser over the remaining input.
+struct Parser<'a> {
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn cl_type(&mut self) -> Result<CLType, ParseTypeError> {
+        let name = self.take_while(|c| c.is_ascii_alphanumeric());
+        let cl_type = match name {
+            "Bool" => CLType::Bool,
+            "I32" => CLType::I32,
+            "I64" => CLType::I64,
+            "U8" => CLType::U8,
+            "U32" => CLType::U32,
+            "U64" => CLType::U64,
+            "U128" => CLType::U128,
+            "U256" => CLType::U256,
+            "U512" => CLType::U512,
+            "Unit" => CLType::Unit,
+            "String" => CLType::String,
+            "Key" => CLType::Key,
+            "URef" => CLType::URef,
+            "PublicKey" => CLType::PublicKey,
+            "Any" => CLType::Any,
+            "ByteArray" => {
+                self.expect("(")?;
+                let len = self
+                    .take_while(|c| c.is_ascii_digit())
+                    .parse()
+                    .map_err(|_| ParseTypeError::Expected("a byte array length"))?;
+                self.expect(")")?;
+                CLType::ByteArray(len)
+            }
+            "Option" => {
+                let [ty] = self.args()?;
+                CLType::Option(ty)
+            }
+            "List" => {
+                let [ty] = self.args()?;
+                CLType::List(ty)
+            }
+            "Result" => {
+                let [ok, err] = self.args()?;
+                CLType::Result { ok, err }
+            }
+            "Map" => {
+                let [key, value] = self.args()?;
+                CLType::Map { key, value }
+            }
+            "Tuple1" => CLType::Tuple1(self.args()?),
+            "Tuple2" => CLType::Tuple2(self.args()?),
+            "Tuple3" => CLType::Tuple3(self.args()?),
+            "" => return Err(ParseTypeError::Expected("a type")),
+            _ => return Err(ParseTypeError::UnknownType(String::from(name))),
+        };
+        Ok(cl_type)
+    }
+
+    /// Parses `N` comma-separated types in angle brackets.
+    fn args<const N: usize>(&mut self) -> Result<[Box<CLType>; N], ParseTypeError> {
+        self.expect("<")?;
+        let mut args = [(); N].map(|_| Box::new(CLType::Unit));
+        for (index, arg) in args.iter_mut().enumerate() {
+            if index > 0 {
+                self.expect(",")?;
+            }
+            **arg = self.cl_type()?;
+        }
+        self.expect(">")?;
+        Ok(args)
+    }
+
+    fn field_name(&mut self) -> Result<&'a str, ParseTypeError> {
+        match self
+            .take_while(|c| !matches!(c, ':' | ',' | '{' | '}'))
+            .trim_end()
+        {
+            "" => Err(ParseTypeError::Expected("a field name")),
+            name => Ok(name),
+        }
+    }
+
+    fn take_while<P: Fn(char) -> bool>(&mut self, predicate: P) -> &'a str {
+        self.input = self.input.trim_start();
+        let end = self
+            .input
+            .find(|c| !predicate(c))
+            .unwrap_or(self.input.len());
+        let (taken, rest) = self.input.split_at(end);
+        self.input = rest;
+        taken
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.input = self.input.trim_start();
+        match self.input.strip_prefix(token) {
+            Some(rest) => {
+                self.input = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), ParseTypeError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(ParseTypeError::Expected(token))
+        }
+    }
+
+    fn end(&mut self) -> Result<(), ParseTypeError> {
+        match self.input.trim() {
+            "" => Ok(()),
+            rest => Err(ParseTypeError::TrailingInput(String::from(rest))),
+        }
+    }
+}
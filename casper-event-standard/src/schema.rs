@@ -7,7 +7,12 @@ use casper_types::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{cl_type2::CLType2, EventInstance};
+use crate::{
+    cl_type2::{self, CLType2},
+    diff::{self, Compatibility, FieldChange, SchemasDiff},
+    error::Error,
+    split_full_name, EventInstances, EVENT_PREFIX,
+};
 
 /// The information about a single event.
 #[derive(Default, Debug, PartialEq, Clone)]
@@ -29,6 +34,16 @@ impl Schema {
     pub fn to_vec(self) -> Vec<(String, CLType2)> {
         self.0
     }
+
+    /// Returns the named elements in the order they were added.
+    pub fn elems(&self) -> &[(String, CLType2)] {
+        &self.0
+    }
+
+    /// Lists the field changes between `self` and the `new` version.
+    pub fn diff(&self, new: &Schema) -> Vec<FieldChange> {
+        diff::diff_schema(self, new)
+    }
 }
 
 impl CLTyped for Schema {
@@ -65,8 +80,35 @@ impl Schemas {
     }
 
     /// Adds new [`Schema`] based on the event's type.
-    pub fn add<T: EventInstance>(&mut self) {
-        self.0.insert(T::name(), T::schema());
+    ///
+    /// For a derived enum, a [`Schema`] for every variant is added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an event with the same name is already registered.
+    /// Use [`try_add`] to handle it.
+    ///
+    /// [`try_add`]: #method.try_add
+    pub fn add<T: EventInstances>(&mut self) {
+        if self.try_add::<T>().is_err() {
+            panic!("Event is already registered.");
+        }
+    }
+
+    /// Adds new [`Schema`] based on the event's type.
+    ///
+    /// Fails with [`Error::DuplicateEvent`] without modifying `self`
+    /// if an event with the same name is already registered.
+    pub fn try_add<T: EventInstances>(&mut self) -> Result<(), Error> {
+        let schemas = T::schemas();
+        let is_duplicate = schemas.iter().enumerate().any(|(index, (name, _))| {
+            self.0.contains_key(name) || schemas[..index].iter().any(|(other, _)| other == name)
+        });
+        if is_duplicate {
+            return Err(Error::DuplicateEvent);
+        }
+        self.0.extend(schemas);
+        Ok(())
     }
 
     /// Adds new [`Schema`] based on the event's type.
@@ -74,10 +116,56 @@ impl Schemas {
     /// Same as [`add`], but returns Self.
     ///
     /// [`add`]: #method.add
-    pub fn with<T: EventInstance>(mut self) -> Self {
+    pub fn with<T: EventInstances>(mut self) -> Self {
         self.add::<T>();
         self
     }
+
+    /// Lists the differences between `self` and the `new` version.
+    pub fn diff(&self, new: &Schemas) -> SchemasDiff {
+        diff::diff_schemas(self, new)
+    }
+
+    /// Checks if `self` can be replaced with the `new` version
+    /// without breaking the decoding of the already emitted events.
+    pub fn compatibility(&self, new: &Schemas) -> Compatibility {
+        self.diff(new).compatibility()
+    }
+
+    /// Adds all the [`Schema`]s from `other`.
+    ///
+    /// Fails with [`Error::IncompatibleSchema`] without modifying `self`
+    /// if an event is already registered with a different [`Schema`].
+    pub fn merge(&mut self, other: Schemas) -> Result<(), Error> {
+        let is_incompatible = other
+            .0
+            .iter()
+            .any(|(name, schema)| matches!(self.0.get(name), Some(current) if current != schema));
+        if is_incompatible {
+            return Err(Error::IncompatibleSchema);
+        }
+        self.0.extend(other.0);
+        Ok(())
+    }
+
+    /// Checks if the serialized event is registered and matches its [`Schema`].
+    pub fn validate(&self, event_bytes: &[u8]) -> Result<(), Error> {
+        let (full_name, mut bytes) =
+            split_full_name(event_bytes).map_err(|_| Error::InvalidEvent)?;
+        let schema = full_name
+            .strip_prefix(EVENT_PREFIX)
+            .and_then(|name| self.0.get(name))
+            .ok_or(Error::UnknownEvent)?;
+
+        for (_, ty) in schema.elems() {
+            bytes = cl_type2::skip_value(&ty.0, bytes).map_err(|_| Error::InvalidEvent)?;
+        }
+        if bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidEvent)
+        }
+    }
 }
 
 impl CLTyped for Schemas {
@@ -0,0 +1,208 @@
+//! Events extracted from the event stream (SSE) of a Casper node.
+//!
+//! The node publishes a `DeployProcessed` or `TransactionProcessed` message
+//! for every executed deploy or transaction. Its execution effects contain
+//! the dictionary writes done by [`emit`](crate::emit), which are decoded here
+//! without a connection to the node, e.g. from a captured stream:
+//!
+//! ```text
+//! curl -sN http://localhost:18101/events/main > events.sse
+//! ```
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    CLValue, URef,
+};
+use core::fmt;
+use serde_json::{Deserializer, Value as JsonValue};
+
+use crate::{
+    decoder::{decode_event, DecodedEvent},
+    hex::parse_hex,
+    schema::Schemas,
+};
+
+/// An event emitted by a deploy or a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SseEvent {
+    /// The hash of the deploy or the transaction that emitted the event.
+    pub hash: String,
+    /// The index of the event in the events dictionary.
+    pub index: u32,
+    /// The event decoded with the given [`Schemas`].
+    pub event: DecodedEvent,
+}
+
+/// The error returned when events can't be extracted from the event stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SseError {
+    /// The input is not valid JSON.
+    Json(String),
+}
+
+impl fmt::Display for SseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SseError::Json(error) => write!(f, "Invalid JSON: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SseError {}
+
+/// Extracts the events written to the `events_dict` dictionary
+/// from the event stream of a node.
+///
+/// The input is either the raw stream, where every message is on a `data:` line,
+/// or JSON messages one after another, e.g. one per line or a JSON array.
+/// Messages other than `DeployProcessed` and `TransactionProcessed`
+/// and failed executions are skipped, and so are the dictionary writes
+/// that are not events decodable with `schemas`, see [`events_from_message`].
+///
+/// The events are returned in the stream order, and in the index order
+/// within a single deploy or transaction.
+pub fn events_from_sse(
+    input: &str,
+    events_dict: URef,
+    schemas: &Schemas,
+) -> Result<Vec<SseEvent>, SseError> {
+    let data = input
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .collect::<Vec<_>>();
+    let messages = if data.is_empty() {
+        Deserializer::from_str(input)
+            .into_iter::<JsonValue>()
+            .collect::<Result<Vec<_>, _>>()
+    } else {
+        data.into_iter().map(serde_json::from_str).collect()
+    }
+    .map_err(|error| SseError::Json(error.to_string()))?;
+
+    let mut events = Vec::new();
+    for message in messages {
+        match message {
+            JsonValue::Array(messages) => {
+                for message in messages {
+                    events.extend(events_from_message(&message, events_dict, schemas));
+                }
+            }
+            message => events.extend(events_from_message(&message, events_dict, schemas)),
+        }
+    }
+    Ok(events)
+}
+
+/// Extracts the events written to the `events_dict` dictionary
+/// from a single `DeployProcessed` or `TransactionProcessed` message.
+///
+/// Returns no events for other messages and failed executions.
+/// Any contract can write to its dictionaries, so a write that is malformed,
+/// belongs to another dictionary or can't be decoded with `schemas` is skipped
+/// instead of hiding the other events of the message.
+pub fn events_from_message(
+    message: &JsonValue,
+    events_dict: URef,
+    schemas: &Schemas,
+) -> Vec<SseEvent> {
+    let (hash, execution_result) = if let Some(deploy) = message.get("DeployProcessed") {
+        (&deploy["deploy_hash"], &deploy["execution_result"])
+    } else if let Some(transaction) = message.get("TransactionProcessed") {
+        (
+            &transaction["transaction_hash"],
+            &transaction["execution_result"],
+        )
+    } else {
+        return Vec::new();
+    };
+    let hash = hash_to_string(hash);
+
+    let mut events = successful_writes(execution_result)
+        .into_iter()
+        .filter(|(key, _)| key.starts_with("dictionary-"))
+        .filter_map(|(_, bytes)| {
+            let (index, value) = parse_event_write(bytes, events_dict)?;
+            let event = decode_event(schemas, &value).ok()?;
+            Some(SseEvent {
+                hash: hash.clone(),
+                index,
+                event,
+            })
+        })
+        .collect::<Vec<_>>();
+    events.sort_by_key(|event| event.index);
+    events
+}
+
+/// Returns the index and the bytes of an event written to the `events_dict` dictionary,
+/// or `None` if the write is not one.
+fn parse_event_write(hex: &str, events_dict: URef) -> Option<(u32, Bytes)> {
+    let bytes = parse_hex(hex)?;
+    let (seed_uref_addr, item_key, cl_value) = parse_dictionary_value(&bytes)?;
+    if seed_uref_addr != events_dict.addr() {
+        return None;
+    }
+    let index = String::from_utf8(item_key).ok()?.parse::<u32>().ok()?;
+    let value = cl_value.into_t::<Bytes>().ok()?;
+    Some((index, value))
+}
+
+/// Returns the keys and the hex bytes of the `CLValue`s written by a successful execution.
+///
+/// Supports the execution results of Casper 1.x, `{"Success": {"effect": {"transforms": ...}}}`,
+/// and the versioned ones of Casper 2.x, `{"Version1": ...}` and `{"Version2": {"effects": ...}}`.
+fn successful_writes(execution_result: &JsonValue) -> Vec<(&str, &str)> {
+    if let Some(result) = execution_result.get("Version1") {
+        return successful_writes(result);
+    }
+    let (transforms, path) = if let Some(success) = execution_result.get("Success") {
+        (
+            &success["effect"]["transforms"],
+            ["transform", "WriteCLValue"],
+        )
+    } else if let Some(result) = execution_result.get("Version2") {
+        if !result["error_message"].is_null() {
+            return Vec::new();
+        }
+        (&result["effects"], ["kind", "Write"])
+    } else {
+        return Vec::new();
+    };
+
+    transforms
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|transform| {
+            let write = &transform[path[0]][path[1]];
+            // Casper 2.x wraps the written value in a `StoredValue`.
+            let cl_value = write.get("CLValue").unwrap_or(write);
+            Some((transform["key"].as_str()?, cl_value["bytes"].as_str()?))
+        })
+        .collect()
+}
+
+/// Splits a serialized `DictionaryValue` into the address of the dictionary seed `URef`,
+/// the item key and the stored value.
+fn parse_dictionary_value(bytes: &[u8]) -> Option<([u8; 32], Vec<u8>, CLValue)> {
+    let (cl_value, remainder) = CLValue::from_bytes(bytes).ok()?;
+    let (seed_uref_addr, remainder) = Bytes::from_bytes(remainder).ok()?;
+    let (item_key, remainder) = Bytes::from_bytes(remainder).ok()?;
+    if !remainder.is_empty() {
+        return None;
+    }
+    let seed_uref_addr = seed_uref_addr[..].try_into().ok()?;
+    Some((seed_uref_addr, item_key.into(), cl_value))
+}
+
+/// Returns the deploy hash, or the hash of a transaction, e.g. `{"Version1": "..."}`.
+fn hash_to_string(hash: &JsonValue) -> String {
+    match hash {
+        JsonValue::String(hash) => hash.clone(),
+        JsonValue::Object(map) => map.values().next().map(hash_to_string).unwrap_or_default(),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,415 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_types::CLType;
+use core::fmt::Write;
+
+use crate::{schema::Schemas, EVENT_PREFIX};
+
+/// The `Reader` class and helpers the generated decoders are built on.
+const PRELUDE: &str = r#"// Generated by casper-event-standard. Do not edit.
+
+export type Result<T, E> = { ok: T } | { err: E };
+
+const KEY_PREFIXES = [
+  "account-hash-",
+  "hash-",
+  "uref-",
+  "transfer-",
+  "deploy-",
+  "era-",
+  "balance-",
+  "bid-",
+  "withdraw-",
+  "dictionary-",
+  "system-contract-registry-",
+  "era-summary-",
+  "unbond-",
+  "chainspec-registry-",
+  "checksum-registry-",
+];
+
+const PUBLIC_KEY_LENGTHS = [0, 32, 33];
+
+function hex(bytes: Uint8Array): string {
+  return Array.from(bytes, (byte) => byte.toString(16).padStart(2, "0")).join("");
+}
+
+/** Reads values serialized with `bytesrepr`. */
+export class Reader {
+  private offset = 0;
+  private readonly view: DataView;
+
+  constructor(private readonly data: Uint8Array) {
+    this.view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  }
+
+  private advance(length: number): number {
+    const offset = this.offset;
+    if (offset + length > this.data.length) {
+      throw new Error("Early end of stream");
+    }
+    this.offset += length;
+    return offset;
+  }
+
+  bool(): boolean {
+    const value = this.u8();
+    if (value > 1) {
+      throw new Error(`Invalid bool: ${value}`);
+    }
+    return value === 1;
+  }
+
+  u8(): number {
+    return this.view.getUint8(this.advance(1));
+  }
+
+  i32(): number {
+    return this.view.getInt32(this.advance(4), true);
+  }
+
+  u32(): number {
+    return this.view.getUint32(this.advance(4), true);
+  }
+
+  i64(): bigint {
+    return this.view.getBigInt64(this.advance(8), true);
+  }
+
+  u64(): bigint {
+    return this.view.getBigUint64(this.advance(8), true);
+  }
+
+  /** Reads `U128`, `U256` or `U512`. */
+  bigUint(): bigint {
+    const bytes = this.bytes(this.u8());
+    let value = BigInt(0);
+    for (let index = bytes.length - 1; index >= 0; index--) {
+      value = (value << BigInt(8)) | BigInt(bytes[index]);
+    }
+    return value;
+  }
+
+  unit(): null {
+    return null;
+  }
+
+  string(): string {
+    const bytes = this.bytes(this.u32());
+    return new TextDecoder("utf-8", { fatal: true }).decode(bytes);
+  }
+
+  bytes(length: number): Uint8Array {
+    const offset = this.advance(length);
+    return this.data.slice(offset, offset + length);
+  }
+
+  /** Reads a `Key` as a formatted string, e.g. `hash-...`. */
+  key(): string {
+    const tag = this.u8();
+    if (tag === 2) {
+      return this.uref();
+    }
+    if (tag === 5) {
+      return `era-${this.u64()}`;
+    }
+    if (tag >= KEY_PREFIXES.length) {
+      throw new Error(`Invalid key tag: ${tag}`);
+    }
+    return KEY_PREFIXES[tag] + hex(this.bytes(32));
+  }
+
+  /** Reads a `URef` as a formatted string, e.g. `uref-...-007`. */
+  uref(): string {
+    const address = hex(this.bytes(32));
+    const accessRights = this.u8().toString(8).padStart(3, "0");
+    return `uref-${address}-${accessRights}`;
+  }
+
+  /** Reads a `PublicKey` as a hex string, starting with the algorithm tag. */
+  publicKey(): string {
+    const tag = this.u8();
+    if (tag >= PUBLIC_KEY_LENGTHS.length) {
+      throw new Error(`Invalid public key tag: ${tag}`);
+    }
+    return hex(Uint8Array.of(tag)) + hex(this.bytes(PUBLIC_KEY_LENGTHS[tag]));
+  }
+
+  option<T>(read: () => T): T | null {
+    const tag = this.u8();
+    switch (tag) {
+      case 0:
+        return null;
+      case 1:
+        return read();
+      default:
+        throw new Error(`Invalid option tag: ${tag}`);
+    }
+  }
+
+  list<T>(read: () => T): Array<T> {
+    const length = this.u32();
+    const values: Array<T> = [];
+    for (let index = 0; index < length; index++) {
+      values.push(read());
+    }
+    return values;
+  }
+
+  /** Reads a `Map` as `[key, value]` pairs in the serialization order. */
+  map<K, V>(readKey: () => K, readValue: () => V): Array<[K, V]> {
+    return this.list<[K, V]>(() => [readKey(), readValue()]);
+  }
+
+  result<T, E>(readOk: () => T, readErr: () => E): Result<T, E> {
+    const tag = this.u8();
+    switch (tag) {
+      case 0:
+        return { err: readErr() };
+      case 1:
+        return { ok: readOk() };
+      default:
+        throw new Error(`Invalid result tag: ${tag}`);
+    }
+  }
+
+  any(): never {
+    throw new Error("CLType Any can't be decoded");
+  }
+
+  expectName(name: string): void {
+    const found = this.string();
+    if (found !== name) {
+      throw new Error(`Expected ${name}, found ${found}`);
+    }
+  }
+
+  expectEnd(): void {
+    if (this.offset !== this.data.length) {
+      throw new Error(`${this.data.length - this.offset} trailing bytes`);
+    }
+  }
+}
+"#;
+
+/// Generates TypeScript interfaces and decoders of all the events in `schemas`.
+///
+/// For every event `Name` the module exports `interface Name` and
+/// `decodeName(bytes: Uint8Array): Name`, which checks the event name and
+/// reads the fields in the schema order, exactly as the derived `FromBytes` does.
+/// `decodeEvent` dispatches on the event name and returns `{ name, data }`.
+/// Names that aren't valid identifiers have other characters replaced with `_`.
+/// Identifiers that clash with another event or with a type of the module,
+/// e.g. `Event`, `Reader` or `string`, get a numeric suffix, e.g. `Event_2`.
+///
+/// * `I32`, `U8` and `U32` are `number`s, the other integers are `bigint`s,
+/// * `Key` and `URef` are formatted strings, e.g. `hash-...`,
+/// * `PublicKey` is a hex string and `ByteArray` is `Uint8Array`,
+/// * `Option` is nullable and `Result` is `{ ok }` or `{ err }`,
+/// * `Map` is an array of `[key, value]` pairs, tuples are fixed-length arrays,
+/// * `Unit` is `null` and `Any` is `unknown`, which can't be decoded.
+pub fn schemas_to_typescript(schemas: &Schemas) -> String {
+    let idents = type_idents(schemas);
+    let mut code = String::from(PRELUDE);
+    for ((name, schema), ident) in schemas.0.iter().zip(&idents) {
+        let _ = writeln!(code, "\nexport interface {} {{", ident);
+        for (field, ty) in schema.elems() {
+            let _ = writeln!(code, "  {}: {};", property(field), ts_type(&ty.0));
+        }
+        code.push_str("}\n");
+
+        let _ = writeln!(
+            code,
+            "\nexport function decode{}(bytes: Uint8Array): {} {{",
+            ident, ident
+        );
+        code.push_str("  const r = new Reader(bytes);\n");
+        let _ = writeln!(
+            code,
+            "  r.expectName({});",
+            string_literal(&format!("{}{}", EVENT_PREFIX, name))
+        );
+        let _ = writeln!(code, "  const event: {} = {{", ident);
+        for (field, ty) in schema.elems() {
+            let _ = writeln!(code, "    {}: {},", property(field), ts_decoder(&ty.0));
+        }
+        code.push_str("  };\n  r.expectEnd();\n  return event;\n}\n");
+    }
+
+    code.push_str("\nexport type Event =");
+    if schemas.0.is_empty() {
+        code.push_str(" never");
+    }
+    for (name, ident) in schemas.0.keys().zip(&idents) {
+        let _ = write!(
+            code,
+            "\n  | {{ name: {}; data: {} }}",
+            string_literal(name),
+            ident
+        );
+    }
+    code.push_str(";\n");
+
+    code.push_str("\nexport function decodeEvent(bytes: Uint8Array): Event {\n");
+    code.push_str("  const name = new Reader(bytes).string();\n  switch (name) {\n");
+    for (name, ident) in schemas.0.keys().zip(&idents) {
+        let _ = writeln!(
+            code,
+            "    case {}:\n      return {{ name: {}, data: decode{}(bytes) }};",
+            string_literal(&format!("{}{}", EVENT_PREFIX, name)),
+            string_literal(name),
+            ident
+        );
+    }
+    code.push_str("    default:\n      throw new Error(`Unknown event: ${name}`);\n  }\n}\n");
+    code
+}
+
+fn ts_type(ty: &CLType) -> String {
+    match ty {
+        CLType::Bool => String::from("boolean"),
+        CLType::I32 | CLType::U8 | CLType::U32 => String::from("number"),
+        CLType::I64 | CLType::U64 | CLType::U128 | CLType::U256 | CLType::U512 => {
+            String::from("bigint")
+        }
+        CLType::Unit => String::from("null"),
+        CLType::String | CLType::Key | CLType::URef | CLType::PublicKey => String::from("string"),
+        CLType::ByteArray(_) => String::from("Uint8Array"),
+        CLType::Option(ty) => format!("{} | null", ts_type(ty)),
+        CLType::List(ty) => format!("Array<{}>", ts_type(ty)),
+        CLType::Result { ok, err } => format!("Result<{}, {}>", ts_type(ok), ts_type(err)),
+        CLType::Map { key, value } => format!("Array<[{}, {}]>", ts_type(key), ts_type(value)),
+        CLType::Tuple1(types) => ts_array(types.iter().map(|ty| ts_type(ty))),
+        CLType::Tuple2(types) => ts_array(types.iter().map(|ty| ts_type(ty))),
+        CLType::Tuple3(types) => ts_array(types.iter().map(|ty| ts_type(ty))),
+        CLType::Any => String::from("unknown"),
+    }
+}
+
+fn ts_decoder(ty: &CLType) -> String {
+    match ty {
+        CLType::Bool => String::from("r.bool()"),
+        CLType::I32 => String::from("r.i32()"),
+        CLType::I64 => String::from("r.i64()"),
+        CLType::U8 => String::from("r.u8()"),
+        CLType::U32 => String::from("r.u32()"),
+        CLType::U64 => String::from("r.u64()"),
+        CLType::U128 | CLType::U256 | CLType::U512 => String::from("r.bigUint()"),
+        CLType::Unit => String::from("r.unit()"),
+        CLType::String => String::from("r.string()"),
+        CLType::Key => String::from("r.key()"),
+        CLType::URef => String::from("r.uref()"),
+        CLType::PublicKey => String::from("r.publicKey()"),
+        CLType::ByteArray(len) => format!("r.bytes({})", len),
+        CLType::Option(ty) => format!("r.option(() => {})", ts_decoder(ty)),
+        CLType::List(ty) => format!("r.list(() => {})", ts_decoder(ty)),
+        CLType::Result { ok, err } => format!(
+            "r.result(() => {}, () => {})",
+            ts_decoder(ok),
+            ts_decoder(err)
+        ),
+        CLType::Map { key, value } => format!(
+            "r.map(() => {}, () => {})",
+            ts_decoder(key),
+            ts_decoder(value)
+        ),
+        // Elements of an array literal are evaluated in order.
+        CLType::Tuple1(types) => ts_array(types.iter().map(|ty| ts_decoder(ty))),
+        CLType::Tuple2(types) => ts_array(types.iter().map(|ty| ts_decoder(ty))),
+        CLType::Tuple3(types) => ts_array(types.iter().map(|ty| ts_decoder(ty))),
+        CLType::Any => String::from("r.any()"),
+    }
+}
+
+/// Joins the elements into an array literal or a tuple type, e.g. `[a, b]`.
+fn ts_array<I: Iterator<Item = String>>(elements: I) -> String {
+    format!("[{}]", elements.collect::<Vec<_>>().join(", "))
+}
+
+/// The types declared or used by the module, which events can't be named after.
+const RESERVED_IDENTS: &[&str] = &[
+    "Array",
+    "DataView",
+    "Event",
+    "Reader",
+    "Result",
+    "Uint8Array",
+    "any",
+    "bigint",
+    "boolean",
+    "never",
+    "null",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+    "unknown",
+    "void",
+];
+
+/// Returns a unique identifier for every event, in the order of `schemas`.
+///
+/// An identifier that is reserved or already taken, e.g. by `a-b` and `a_b`
+/// that both become `a_b`, gets the first free suffix `_2`, `_3`, ...
+fn type_idents(schemas: &Schemas) -> Vec<String> {
+    let mut idents: Vec<String> = Vec::new();
+    for name in schemas.0.keys() {
+        let base = type_ident(name);
+        let is_free =
+            |ident: &str| !RESERVED_IDENTS.contains(&ident) && !idents.iter().any(|i| i == ident);
+        let mut ident = base.clone();
+        let mut suffix = 2;
+        while !is_free(&ident) {
+            ident = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        idents.push(ident);
+    }
+    idents
+}
+
+/// Turns an event name into a valid TypeScript identifier.
+fn type_ident(name: &str) -> String {
+    let mut ident = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !ident.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Returns the field name as is if it's a valid identifier, quoted otherwise.
+fn property(name: &str) -> String {
+    let is_ident = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_ident {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(literal, "\\u{{{:x}}}", c as u32);
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
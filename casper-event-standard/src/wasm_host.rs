@@ -0,0 +1,54 @@
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    ApiError, CLTyped, URef,
+};
+
+use crate::{error::Error, host::Host};
+
+/// [`Host`] backed by the contract runtime.
+pub(crate) struct WasmHost;
+
+impl Host for WasmHost {
+    type Key = URef;
+
+    fn has_key(&self, name: &str) -> bool {
+        runtime::has_key(name)
+    }
+
+    fn get_key(&self, name: &str) -> Result<Option<URef>, Error> {
+        runtime::get_key(name)
+            .map(|key| key.try_into().map_err(|_| Error::InvalidNamedKey))
+            .transpose()
+    }
+
+    fn new_dictionary(&mut self, name: &str) -> Result<(), Error> {
+        storage::new_dictionary(name).map_err(|_| Error::InvalidNamedKey)?;
+        Ok(())
+    }
+
+    fn put_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) -> Result<(), Error> {
+        runtime::put_key(name, storage::new_uref(value).into());
+        Ok(())
+    }
+
+    fn read_value<T: CLTyped + FromBytes>(&self, uref: &URef) -> Result<T, Error> {
+        storage::read(*uref)
+            .map_err(|_| Error::InvalidNamedKey)?
+            .ok_or(Error::InvalidNamedKey)
+    }
+
+    fn write_value<T: CLTyped + ToBytes>(&mut self, uref: &URef, value: T) -> Result<(), Error> {
+        storage::write(*uref, value);
+        Ok(())
+    }
+
+    fn dictionary_put(&mut self, dictionary: &URef, key: &str, value: Bytes) -> Result<(), Error> {
+        storage::dictionary_put(*dictionary, key, value);
+        Ok(())
+    }
+
+    fn revert(&self, error: ApiError) -> ! {
+        runtime::revert(error)
+    }
+}
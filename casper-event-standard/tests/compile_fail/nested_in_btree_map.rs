@@ -0,0 +1,16 @@
+use std::collections::BTreeMap;
+
+use casper_event_standard::Event;
+use casper_types::U256;
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+}
+
+#[derive(Event)]
+struct Wrapper {
+    transfer: BTreeMap<String, Transfer>,
+}
+
+fn main() {}
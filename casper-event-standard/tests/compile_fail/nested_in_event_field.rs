@@ -0,0 +1,20 @@
+use casper_event_standard::{Event, EventField};
+use casper_types::U256;
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+}
+
+#[derive(EventField)]
+struct Labeled<T> {
+    label: String,
+    value: T,
+}
+
+#[derive(Event)]
+struct Wrapper {
+    transfer: Labeled<Transfer>,
+}
+
+fn main() {}
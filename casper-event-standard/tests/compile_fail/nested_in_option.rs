@@ -0,0 +1,14 @@
+use casper_event_standard::Event;
+use casper_types::U256;
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+}
+
+#[derive(Event)]
+struct Wrapper {
+    transfer: Option<Transfer>,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[test]
+fn test_nested_events_fail_to_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+
+use casper_event_standard::{
+    decode_event, decode_value, DecodeError, Event, EventField, Schemas, Value,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    CLType, Key, U256,
+};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event)]
+struct Complex {
+    memo: Option<String>,
+    ids: Vec<u32>,
+    balances: BTreeMap<String, u64>,
+    status: Result<u8, String>,
+    pair: (u8, bool),
+    hash: [u8; 4],
+}
+
+fn mock_transfer() -> Transfer {
+    Transfer {
+        amount: U256::from(123),
+        from: Key::from_formatted_str(
+            "hash-1111111111111111111111111111111111111111111111111111111111111111",
+        )
+        .unwrap(),
+        to: Key::from_formatted_str(
+            "hash-2222222222222222222222222222222222222222222222222222222222222222",
+        )
+        .unwrap(),
+    }
+}
+
+fn mock_complex() -> Complex {
+    let mut balances = BTreeMap::new();
+    balances.insert(String::from("alice"), 10);
+    balances.insert(String::from("bob"), 20);
+    Complex {
+        memo: Some(String::from("memo")),
+        ids: vec![1, 2, 3],
+        balances,
+        status: Err(String::from("failed")),
+        pair: (7, true),
+        hash: [1, 2, 3, 4],
+    }
+}
+
+#[test]
+fn test_decode_event() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let transfer = mock_transfer();
+    let event = decode_event(&schemas, &transfer.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(event.name(), "Transfer");
+    assert_eq!(
+        event.fields(),
+        &[
+            (String::from("amount"), Value::U256(transfer.amount)),
+            (String::from("from"), Value::Key(transfer.from)),
+            (String::from("to"), Value::Key(transfer.to)),
+        ]
+    );
+    assert_eq!(event.field("to"), Some(&Value::Key(transfer.to)));
+    assert_eq!(event.field("memo"), None);
+}
+
+#[test]
+fn test_decode_complex_event() {
+    let schemas = Schemas::new().with::<Complex>();
+    let event = decode_event(&schemas, &mock_complex().to_bytes().unwrap()).unwrap();
+
+    assert_eq!(event.name(), "Complex");
+    assert_eq!(
+        event.field("memo"),
+        Some(&Value::Option(Some(Box::new(Value::String(String::from(
+            "memo"
+        ))))))
+    );
+    assert_eq!(
+        event.field("ids"),
+        Some(&Value::List(vec![
+            Value::U32(1),
+            Value::U32(2),
+            Value::U32(3)
+        ]))
+    );
+    assert_eq!(
+        event.field("balances"),
+        Some(&Value::Map(vec![
+            (Value::String(String::from("alice")), Value::U64(10)),
+            (Value::String(String::from("bob")), Value::U64(20)),
+        ]))
+    );
+    assert_eq!(
+        event.field("status"),
+        Some(&Value::Result(Err(Box::new(Value::String(String::from(
+            "failed"
+        ))))))
+    );
+    assert_eq!(
+        event.field("pair"),
+        Some(&Value::Tuple(vec![Value::U8(7), Value::Bool(true)]))
+    );
+    assert_eq!(
+        event.field("hash"),
+        Some(&Value::ByteArray(vec![1, 2, 3, 4]))
+    );
+}
+
+#[test]
+fn test_decode_unknown_event() {
+    let schemas = Schemas::new().with::<Complex>();
+    let result = decode_event(&schemas, &mock_transfer().to_bytes().unwrap());
+    assert_eq!(
+        result,
+        Err(DecodeError::UnknownEvent(String::from("Transfer")))
+    );
+}
+
+#[test]
+fn test_decode_invalid_name() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let bytes = "Transfer".to_bytes().unwrap();
+    let result = decode_event(&schemas, &bytes);
+    assert_eq!(
+        result,
+        Err(DecodeError::InvalidName(String::from("Transfer")))
+    );
+}
+
+#[test]
+fn test_decode_trailing_bytes() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let mut bytes = mock_transfer().to_bytes().unwrap();
+    bytes.extend([0, 0]);
+    let result = decode_event(&schemas, &bytes);
+    assert_eq!(result, Err(DecodeError::TrailingBytes(2)));
+}
+
+#[test]
+fn test_decode_truncated_event() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let bytes = mock_transfer().to_bytes().unwrap();
+    let result = decode_event(&schemas, &bytes[..bytes.len() - 1]);
+    assert_eq!(
+        result,
+        Err(DecodeError::Bytesrepr(bytesrepr::Error::EarlyEndOfStream))
+    );
+}
+
+#[test]
+fn test_decode_huge_list_of_units() {
+    // The length prefix claims 4 billion elements that take no bytes.
+    let bytes = u32::MAX.to_bytes().unwrap();
+    let ty = CLType::List(Box::new(CLType::Unit));
+    assert_eq!(
+        decode_value(&ty, &bytes),
+        Err(DecodeError::Bytesrepr(bytesrepr::Error::EarlyEndOfStream))
+    );
+
+    let ty = CLType::Map {
+        key: Box::new(CLType::Unit),
+        value: Box::new(CLType::Unit),
+    };
+    assert_eq!(
+        decode_value(&ty, &bytes),
+        Err(DecodeError::Bytesrepr(bytesrepr::Error::EarlyEndOfStream))
+    );
+}
+
+#[test]
+fn test_decode_any() {
+    let result = decode_value(&CLType::Any, &[]);
+    assert_eq!(result, Err(DecodeError::UnsupportedType(CLType::Any)));
+}
+
+#[derive(EventField)]
+struct Metadata {
+    name: String,
+    decimals: u8,
+}
+
+#[derive(Event)]
+struct Created {
+    metadata: Metadata,
+}
+
+#[test]
+fn test_decode_event_field() {
+    let schemas = Schemas::new().with::<Created>();
+    let event = Created {
+        metadata: Metadata {
+            name: String::from("Token"),
+            decimals: 9,
+        },
+    };
+    let event = decode_event(&schemas, &event.to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        event.field("metadata"),
+        Some(&Value::Tuple(vec![
+            Value::String(String::from("Token")),
+            Value::U8(9)
+        ]))
+    );
+}
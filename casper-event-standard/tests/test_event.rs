@@ -1,9 +1,12 @@
 use std::collections::BTreeMap;
 
-use casper_event_standard::{try_full_name_from_bytes, Event, EventInstance, Schema};
+use casper_event_standard::{
+    from_event_bytes, try_full_name_from_bytes, Error, Event, EventField, EventInstance, EventSet,
+    FromBytesLenient, HasAny, Schema, Schemas,
+};
 use casper_types::{
-    bytesrepr::{FromBytes, ToBytes},
-    CLTyped, Key, U256,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLTyped, Key, U256, U512,
 };
 
 #[derive(Event, Debug, PartialEq)]
@@ -56,9 +59,11 @@ fn test_event_schema() {
 
 #[test]
 fn test_serialization_of_any() {
+    // Events nested in events fail the build, unless hidden behind
+    // a type parameter or a type that doesn't implement `HasAny`.
     #[derive(Event)]
-    struct Simple {
-        transfer: Transfer,
+    struct Simple<T> {
+        transfer: T,
     }
 
     let event = Simple {
@@ -69,16 +74,483 @@ fn test_serialization_of_any() {
         Err(casper_types::bytesrepr::Error::Formatting)
     );
 
+    struct Opaque;
+
+    impl CLTyped for Opaque {
+        fn cl_type() -> casper_types::CLType {
+            casper_types::CLType::Any
+        }
+    }
+
+    impl ToBytes for Opaque {
+        fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+            Ok(Vec::new())
+        }
+
+        fn serialized_length(&self) -> usize {
+            0
+        }
+    }
+
+    impl FromBytes for Opaque {
+        fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+            Ok((Opaque, bytes))
+        }
+    }
+
     #[derive(Event)]
     struct Complex {
-        transfers: BTreeMap<(u32,), Result<Vec<Transfer>, String>>,
+        opaque: Vec<Opaque>,
     }
 
-    let event = Complex {
-        transfers: BTreeMap::new(),
-    };
+    let event = Complex { opaque: Vec::new() };
     assert_eq!(
         event.to_bytes(),
         Err(casper_types::bytesrepr::Error::Formatting)
     );
 }
+
+fn has_any<T: HasAny>() -> bool {
+    T::HAS_ANY
+}
+
+#[test]
+fn test_has_any() {
+    assert!(has_any::<Transfer>());
+    assert!(has_any::<Option<Vec<Transfer>>>());
+    assert!(has_any::<BTreeMap<(u32,), Result<Vec<Transfer>, String>>>());
+    assert!(!has_any::<BTreeMap<(u32,), Result<Vec<U256>, String>>>());
+    assert!(!has_any::<(Key, Option<[u8; 32]>, bool)>());
+}
+
+#[derive(Event, Debug, PartialEq)]
+enum TokenEvent {
+    Mint { account: Key, amount: U256 },
+    Burn { account: Key },
+}
+
+fn mock_mint() -> TokenEvent {
+    TokenEvent::Mint {
+        account: Key::from_formatted_str(
+            "hash-3333333333333333333333333333333333333333333333333333333333333333",
+        )
+        .unwrap(),
+        amount: U256::from(234),
+    }
+}
+
+#[test]
+fn test_enum_event_serialization() {
+    let expected = mock_mint();
+    let bytes = expected.to_bytes().unwrap();
+    assert_eq!(bytes.len(), expected.serialized_length());
+    assert_eq!(&try_full_name_from_bytes(&bytes).unwrap(), "event_Mint");
+    let (result, bytes) = TokenEvent::from_bytes(&bytes).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_enum_event_from_unknown_name() {
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(
+        TokenEvent::from_bytes(&bytes),
+        Err(casper_types::bytesrepr::Error::Formatting)
+    );
+}
+
+#[test]
+fn test_enum_event_has_any() {
+    assert!(has_any::<TokenEvent>());
+}
+
+#[test]
+fn test_enum_event_schemas() {
+    let mut mint_schema = Schema::new();
+    mint_schema.with_elem("account", Key::cl_type());
+    mint_schema.with_elem("amount", U256::cl_type());
+    let mut burn_schema = Schema::new();
+    burn_schema.with_elem("account", Key::cl_type());
+
+    let schemas = Schemas::new().with::<TokenEvent>().with::<Transfer>();
+    let mut expected = BTreeMap::new();
+    expected.insert(String::from("Mint"), mint_schema);
+    expected.insert(String::from("Burn"), burn_schema);
+    expected.insert(String::from("Transfer"), Transfer::schema());
+    assert_eq!(schemas, Schemas(expected));
+}
+
+#[derive(Debug, PartialEq)]
+struct Amount(U256);
+
+impl ToBytes for Amount {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Amount {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        U256::from_bytes(bytes).map(|(value, bytes)| (Amount(value), bytes))
+    }
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct Deposit {
+    #[event(rename = "from_account")]
+    from: Key,
+    #[event(cl_type = U256)]
+    amount: Amount,
+    #[event(skip)]
+    note: Option<String>,
+}
+
+fn mock_deposit() -> Deposit {
+    Deposit {
+        from: Key::from_formatted_str(
+            "hash-1111111111111111111111111111111111111111111111111111111111111111",
+        )
+        .unwrap(),
+        amount: Amount(U256::from(123)),
+        note: Some(String::from("note")),
+    }
+}
+
+#[test]
+fn test_field_attributes_serialization() {
+    let deposit = mock_deposit();
+    let bytes = deposit.to_bytes().unwrap();
+    assert_eq!(bytes.len(), deposit.serialized_length());
+
+    let mut expected = Vec::new();
+    expected.extend("event_Deposit".to_bytes().unwrap());
+    expected.extend(deposit.from.to_bytes().unwrap());
+    expected.extend(deposit.amount.to_bytes().unwrap());
+    assert_eq!(bytes, expected);
+
+    let (result, bytes) = Deposit::from_bytes(&bytes).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(
+        result,
+        Deposit {
+            note: None,
+            ..mock_deposit()
+        }
+    );
+}
+
+#[test]
+fn test_field_attributes_schema() {
+    let mut expected_schema = Schema::new();
+    expected_schema.with_elem("from_account", Key::cl_type());
+    expected_schema.with_elem("amount", U256::cl_type());
+    assert_eq!(Deposit::schema(), expected_schema);
+}
+
+#[derive(Event, Debug, PartialEq)]
+#[event(name = "TokenTransfer")]
+struct RenamedTransfer {
+    amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq)]
+enum RenamedTokenEvent {
+    #[event(name = "TokenMint")]
+    Mint {
+        amount: U256,
+    },
+    Burn {
+        amount: U256,
+    },
+}
+
+#[test]
+fn test_renamed_event() {
+    let event = RenamedTransfer {
+        amount: U256::from(1),
+    };
+    let bytes = event.to_bytes().unwrap();
+    assert_eq!(bytes.len(), event.serialized_length());
+    assert_eq!(
+        &try_full_name_from_bytes(&bytes).unwrap(),
+        "event_TokenTransfer"
+    );
+    let (result, bytes) = RenamedTransfer::from_bytes(&bytes).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(result, event);
+    assert_eq!(&RenamedTransfer::name(), "TokenTransfer");
+}
+
+#[test]
+fn test_renamed_enum_variant() {
+    let event = RenamedTokenEvent::Mint {
+        amount: U256::from(1),
+    };
+    let bytes = event.to_bytes().unwrap();
+    assert_eq!(
+        &try_full_name_from_bytes(&bytes).unwrap(),
+        "event_TokenMint"
+    );
+    let (result, bytes) = RenamedTokenEvent::from_bytes(&bytes).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(result, event);
+
+    let schemas = Schemas::new().with::<RenamedTokenEvent>();
+    assert!(schemas.0.contains_key("TokenMint"));
+    assert!(schemas.0.contains_key("Burn"));
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct OtherTransfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[test]
+fn test_event_from_other_name() {
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(
+        OtherTransfer::from_bytes(&bytes),
+        Err(casper_types::bytesrepr::Error::Formatting)
+    );
+
+    let (result, bytes) = OtherTransfer::from_bytes_lenient(&bytes).unwrap();
+    assert!(bytes.is_empty());
+    let transfer = mock_transfer();
+    assert_eq!(
+        result,
+        OtherTransfer {
+            amount: transfer.amount,
+            from: transfer.from,
+            to: transfer.to,
+        }
+    );
+}
+
+#[test]
+fn test_from_event_bytes() {
+    let bytes = mock_transfer().to_bytes().unwrap();
+    let (result, rest) = from_event_bytes::<Transfer>(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, mock_transfer());
+
+    assert_eq!(
+        from_event_bytes::<OtherTransfer>(&bytes),
+        Err(Error::UnexpectedEvent)
+    );
+    assert_eq!(
+        from_event_bytes::<TokenEvent>(&bytes),
+        Err(Error::UnexpectedEvent)
+    );
+    assert_eq!(
+        from_event_bytes::<Transfer>(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidEvent)
+    );
+
+    let bytes = mock_mint().to_bytes().unwrap();
+    let (result, _) = from_event_bytes::<TokenEvent>(&bytes).unwrap();
+    assert_eq!(result, mock_mint());
+}
+
+#[derive(EventSet, Debug, PartialEq)]
+enum AllEvents {
+    Transfer(Transfer),
+    Token(TokenEvent),
+}
+
+#[test]
+fn test_event_set_from_event_bytes() {
+    let bytes = mock_transfer().to_bytes().unwrap();
+    let (result, rest) = AllEvents::from_event_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, AllEvents::Transfer(mock_transfer()));
+
+    let bytes = mock_mint().to_bytes().unwrap();
+    let (result, rest) = AllEvents::from_event_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, AllEvents::Token(mock_mint()));
+
+    let bytes = OtherTransfer {
+        amount: U256::from(1),
+        from: mock_transfer().from,
+        to: mock_transfer().to,
+    }
+    .to_bytes()
+    .unwrap();
+    assert_eq!(
+        AllEvents::from_event_bytes(&bytes),
+        Err(Error::UnexpectedEvent)
+    );
+
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(
+        AllEvents::from_event_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidEvent)
+    );
+}
+
+#[test]
+fn test_event_set_schemas() {
+    let expected = Schemas::new().with::<Transfer>().with::<TokenEvent>();
+    assert_eq!(AllEvents::all_schemas(), expected);
+    assert_eq!(Schemas::new().with::<AllEvents>(), expected);
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct GenericTransfer<T> {
+    amount: T,
+    to: Key,
+}
+
+#[derive(Event, Debug, PartialEq)]
+enum GenericTokenEvent<T>
+where
+    T: Copy,
+{
+    Mint { amount: T },
+    Burn { amount: T },
+}
+
+#[test]
+fn test_generic_event() {
+    let event = GenericTransfer {
+        amount: U512::from(7),
+        to: mock_transfer().to,
+    };
+    let bytes = event.to_bytes().unwrap();
+    assert_eq!(bytes.len(), event.serialized_length());
+    let (result, rest) = GenericTransfer::<U512>::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, event);
+
+    assert_eq!(GenericTransfer::<U512>::name(), "GenericTransfer");
+    assert_eq!(GenericTransfer::<u64>::name(), "GenericTransfer");
+
+    let mut expected_schema = Schema::new();
+    expected_schema.with_elem("amount", U512::cl_type());
+    expected_schema.with_elem("to", Key::cl_type());
+    assert_eq!(GenericTransfer::<U512>::schema(), expected_schema);
+}
+
+#[test]
+fn test_generic_enum_event() {
+    let event = GenericTokenEvent::Burn { amount: 5u64 };
+    let bytes = event.to_bytes().unwrap();
+    let (result, rest) = GenericTokenEvent::<u64>::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, event);
+
+    let schemas = Schemas::new().with::<GenericTokenEvent<u64>>();
+    let mut expected_schema = Schema::new();
+    expected_schema.with_elem("amount", u64::cl_type());
+    assert_eq!(schemas.0.get("Mint"), Some(&expected_schema));
+    assert_eq!(schemas.0.get("Burn"), Some(&expected_schema));
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct Paused;
+
+#[derive(Event, Debug, PartialEq)]
+struct OwnerChanged(Key, #[event(rename = "new_owner")] Key);
+
+#[test]
+fn test_unit_event() {
+    let bytes = Paused.to_bytes().unwrap();
+    assert_eq!(bytes, "event_Paused".to_bytes().unwrap());
+    let (result, rest) = Paused::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, Paused);
+    assert_eq!(Paused::schema(), Schema::new());
+}
+
+#[test]
+fn test_tuple_event() {
+    let transfer = mock_transfer();
+    let event = OwnerChanged(transfer.from, transfer.to);
+    let bytes = event.to_bytes().unwrap();
+    assert_eq!(bytes.len(), event.serialized_length());
+    let (result, rest) = OwnerChanged::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, event);
+
+    let mut expected_schema = Schema::new();
+    expected_schema.with_elem("0", Key::cl_type());
+    expected_schema.with_elem("new_owner", Key::cl_type());
+    assert_eq!(OwnerChanged::schema(), expected_schema);
+}
+
+#[derive(EventField, Debug, PartialEq, Clone)]
+struct TokenMetadata {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    total_supply: U256,
+}
+
+#[derive(EventField)]
+struct Labeled<T> {
+    label: String,
+    value: T,
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct TokenCreated {
+    metadata: TokenMetadata,
+    previous: Option<TokenMetadata>,
+}
+
+fn mock_metadata() -> TokenMetadata {
+    TokenMetadata {
+        name: String::from("Token"),
+        symbol: String::from("TKN"),
+        decimals: 9,
+        total_supply: U256::from(1_000),
+    }
+}
+
+#[test]
+fn test_event_field_serialization() {
+    let metadata = mock_metadata();
+    let bytes = metadata.to_bytes().unwrap();
+    assert_eq!(bytes.len(), metadata.serialized_length());
+    let expected = (
+        metadata.name.clone(),
+        metadata.symbol.clone(),
+        (metadata.decimals, metadata.total_supply),
+    )
+        .to_bytes()
+        .unwrap();
+    assert_eq!(bytes, expected);
+
+    let event = TokenCreated {
+        metadata: mock_metadata(),
+        previous: Some(mock_metadata()),
+    };
+    let bytes = event.to_bytes().unwrap();
+    let (result, rest) = TokenCreated::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(result, event);
+}
+
+#[test]
+fn test_event_field_schema() {
+    assert_eq!(
+        TokenMetadata::cl_type(),
+        <(String, String, (u8, U256))>::cl_type()
+    );
+    assert!(!has_any::<TokenMetadata>());
+
+    assert!(!has_any::<Labeled<u8>>());
+    assert!(has_any::<Labeled<Transfer>>());
+    assert!(has_any::<Option<Labeled<Vec<Transfer>>>>());
+
+    let mut expected_schema = Schema::new();
+    expected_schema.with_elem("metadata", TokenMetadata::cl_type());
+    expected_schema.with_elem("previous", Option::<TokenMetadata>::cl_type());
+    assert_eq!(TokenCreated::schema(), expected_schema);
+}
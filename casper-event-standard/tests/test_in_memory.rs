@@ -0,0 +1,410 @@
+use casper_event_standard::{
+    in_memory, Error, Event, EventKeys, EventsMetadata, Layout, Schema, Schemas, CES_VERSION,
+    CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH, EVENTS_METADATA, EVENTS_SCHEMA,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLTyped, Key, U256,
+};
+
+#[derive(Event, Debug, PartialEq)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event, Debug, PartialEq)]
+struct Mint {
+    account: Key,
+    amount: U256,
+}
+
+/// An event that always fails to serialize.
+struct Unserializable;
+
+impl ToBytes for Unserializable {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Err(bytesrepr::Error::Formatting)
+    }
+
+    fn serialized_length(&self) -> usize {
+        0
+    }
+}
+
+fn hash_key(byte: &str) -> Key {
+    Key::from_formatted_str(&format!("hash-{}", byte.repeat(64))).unwrap()
+}
+
+fn mock_transfer() -> Transfer {
+    Transfer {
+        amount: U256::from(123),
+        from: hash_key("1"),
+        to: hash_key("2"),
+    }
+}
+
+fn mock_mint() -> Mint {
+    Mint {
+        account: hash_key("3"),
+        amount: U256::from(234),
+    }
+}
+
+fn schemas() -> Schemas {
+    Schemas::new().with::<Transfer>().with::<Mint>()
+}
+
+#[test]
+fn test_init() {
+    casper_event_standard::init(schemas());
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+    assert!(in_memory::emitted_events().is_empty());
+    assert_eq!(
+        in_memory::named_keys(),
+        vec![EVENTS_DICT, CES_VERSION_KEY, EVENTS_LENGTH, EVENTS_SCHEMA]
+    );
+}
+
+#[test]
+fn test_emit() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::emit(mock_transfer());
+    casper_event_standard::emit(mock_mint());
+
+    let events = in_memory::emitted_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(*events[0], mock_transfer().to_bytes().unwrap());
+    let (mint, bytes) = Mint::from_bytes(&events[1]).unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(mint, mock_mint());
+}
+
+#[test]
+fn test_clear() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::emit(mock_transfer());
+    in_memory::clear();
+
+    assert_eq!(in_memory::schemas(), None);
+    assert!(in_memory::emitted_events().is_empty());
+
+    casper_event_standard::init(schemas());
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64006)")]
+fn test_double_init() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::init(schemas());
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64005)")]
+fn test_emit_without_init() {
+    casper_event_standard::emit(mock_transfer());
+}
+
+#[test]
+fn test_emit_checked() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::emit_checked(mock_transfer());
+    assert_eq!(in_memory::emitted_events().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64000)")]
+fn test_emit_checked_unknown_event() {
+    casper_event_standard::init(Schemas::new().with::<Mint>());
+    casper_event_standard::emit_checked(mock_transfer());
+}
+
+#[test]
+fn test_register_schemas() {
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    casper_event_standard::emit(mock_transfer());
+    casper_event_standard::register_schemas(Schemas::new().with::<Mint>());
+    casper_event_standard::emit_checked(mock_mint());
+
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+    assert_eq!(in_memory::emitted_events().len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64002)")]
+fn test_register_incompatible_schemas() {
+    let mut schema = Schema::new();
+    schema.with_elem("amount", U256::cl_type());
+    let mut schemas = Schemas::new();
+    schemas.0.insert(String::from("Transfer"), schema);
+
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    casper_event_standard::register_schemas(schemas);
+}
+
+#[test]
+fn test_streams_with_prefix() {
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    casper_event_standard::init_with_prefix("minting", Schemas::new().with::<Mint>());
+    casper_event_standard::emit(mock_transfer());
+    casper_event_standard::emit_to("minting", mock_mint());
+    casper_event_standard::emit_checked_to("minting", mock_mint());
+
+    assert_eq!(
+        in_memory::emitted_events(),
+        vec![mock_transfer().to_bytes().unwrap().into()]
+    );
+    assert_eq!(in_memory::emitted_events_with_prefix("minting").len(), 2);
+    assert_eq!(
+        in_memory::schemas(),
+        Some(Schemas::new().with::<Transfer>())
+    );
+    assert_eq!(
+        in_memory::schemas_with_prefix("minting"),
+        Some(Schemas::new().with::<Mint>())
+    );
+
+    casper_event_standard::register_schemas_with_prefix(
+        "minting",
+        Schemas::new().with::<Transfer>(),
+    );
+    assert_eq!(in_memory::schemas_with_prefix("minting"), Some(schemas()));
+    assert_eq!(
+        in_memory::schemas(),
+        Some(Schemas::new().with::<Transfer>())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64000)")]
+fn test_emit_checked_to_other_stream() {
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    casper_event_standard::init_with_prefix("minting", Schemas::new().with::<Mint>());
+    casper_event_standard::emit_checked_to("minting", mock_transfer());
+}
+
+#[test]
+fn test_event_keys() {
+    let keys = EventKeys::with_prefix("minting");
+    assert_eq!(keys.events_dict(), "minting__events");
+    assert_eq!(keys.events_length(), "minting__events_length");
+    assert_eq!(keys.events_schema(), "minting__events_schema");
+    assert_eq!(keys.ces_version(), "minting__events_ces_version");
+    assert_eq!(keys.events_metadata(), "minting__events_metadata");
+
+    let keys = EventKeys::default();
+    assert_eq!(keys.events_dict(), EVENTS_DICT);
+    assert_eq!(keys.events_length(), EVENTS_LENGTH);
+    assert_eq!(keys.events_schema(), EVENTS_SCHEMA);
+    assert_eq!(keys.ces_version(), CES_VERSION_KEY);
+    assert_eq!(keys.events_metadata(), EVENTS_METADATA);
+}
+
+#[test]
+fn test_emit_batch() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::emit(mock_mint());
+    let transfers = (1..=3).map(|amount| Transfer {
+        amount: U256::from(amount),
+        ..mock_transfer()
+    });
+    casper_event_standard::emit_batch(transfers);
+    casper_event_standard::emit_batch(Vec::<Mint>::new());
+    casper_event_standard::emit(mock_mint());
+
+    let events = in_memory::emitted_events();
+    assert_eq!(events.len(), 5);
+    for (index, amount) in (1..=3).enumerate() {
+        let (transfer, _) = Transfer::from_bytes(&events[index + 1]).unwrap();
+        assert_eq!(transfer.amount, U256::from(amount));
+    }
+    assert_eq!(*events[4], mock_mint().to_bytes().unwrap());
+}
+
+#[test]
+fn test_emit_empty_batch_skips_storage() {
+    assert_eq!(
+        casper_event_standard::try_emit_batch(Vec::<Mint>::new()),
+        Ok(())
+    );
+    assert!(in_memory::named_keys().is_empty());
+}
+
+#[test]
+fn test_emit_batch_to() {
+    casper_event_standard::init_with_prefix("minting", schemas());
+    casper_event_standard::emit_batch_to("minting", vec![mock_mint(), mock_mint()]);
+    assert_eq!(in_memory::emitted_events_with_prefix("minting").len(), 2);
+}
+
+#[test]
+fn test_try_init() {
+    assert_eq!(casper_event_standard::try_init(schemas()), Ok(()));
+    assert_eq!(
+        casper_event_standard::try_init(schemas()),
+        Err(Error::AlreadyInitialized)
+    );
+    assert_eq!(
+        casper_event_standard::try_init_with_prefix("minting", schemas()),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_error_display() {
+    let error: Box<dyn std::error::Error> = casper_event_standard::try_emit(mock_transfer())
+        .unwrap_err()
+        .into();
+    assert_eq!(error.to_string(), "Events are not initialized");
+}
+
+#[test]
+fn test_try_emit() {
+    assert_eq!(
+        casper_event_standard::try_emit(mock_transfer()),
+        Err(Error::NotInitialized)
+    );
+    casper_event_standard::init(schemas());
+    assert_eq!(casper_event_standard::try_emit(mock_transfer()), Ok(()));
+    assert_eq!(
+        casper_event_standard::try_emit(Unserializable),
+        Err(Error::Serialization)
+    );
+    assert_eq!(
+        casper_event_standard::try_emit_to("minting", mock_mint()),
+        Err(Error::NotInitialized)
+    );
+    assert_eq!(in_memory::emitted_events().len(), 1);
+}
+
+#[test]
+fn test_try_emit_checked() {
+    casper_event_standard::init(Schemas::new().with::<Mint>());
+    assert_eq!(
+        casper_event_standard::try_emit_checked(mock_transfer()),
+        Err(Error::UnknownEvent)
+    );
+    assert_eq!(casper_event_standard::try_emit_checked(mock_mint()), Ok(()));
+    assert_eq!(in_memory::emitted_events().len(), 1);
+}
+
+#[test]
+fn test_try_register_schemas() {
+    assert_eq!(
+        casper_event_standard::try_register_schemas(schemas()),
+        Err(Error::NotInitialized)
+    );
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    assert_eq!(
+        casper_event_standard::try_register_schemas(Schemas::new().with::<Mint>()),
+        Ok(())
+    );
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+}
+
+#[test]
+fn test_try_emit_batch_fails_without_emitting() {
+    casper_event_standard::init(schemas());
+    assert_eq!(
+        casper_event_standard::try_emit_batch(vec![Ok(mock_mint()), Err(Unserializable)]),
+        Err(Error::Serialization)
+    );
+    assert!(in_memory::emitted_events().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Reverted with User(64007)")]
+fn test_emit_unserializable() {
+    casper_event_standard::init(schemas());
+    casper_event_standard::emit(Unserializable);
+}
+
+#[test]
+fn test_emit_or_init() {
+    casper_event_standard::emit_or_init(mock_transfer(), schemas);
+    casper_event_standard::emit_or_init(mock_mint(), || unreachable!());
+    casper_event_standard::emit_checked(mock_mint());
+
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+    assert_eq!(in_memory::emitted_events().len(), 3);
+}
+
+#[test]
+fn test_emit_or_init_after_init() {
+    casper_event_standard::init(Schemas::new().with::<Transfer>());
+    casper_event_standard::emit_or_init(mock_mint(), schemas);
+    assert_eq!(
+        in_memory::schemas(),
+        Some(Schemas::new().with::<Transfer>())
+    );
+    assert_eq!(in_memory::emitted_events().len(), 1);
+}
+
+#[test]
+fn test_emit_or_init_to() {
+    casper_event_standard::emit_or_init_to("minting", mock_mint(), Schemas::new);
+    assert_eq!(
+        in_memory::schemas_with_prefix("minting"),
+        Some(Schemas::new())
+    );
+    assert_eq!(in_memory::emitted_events_with_prefix("minting").len(), 1);
+    assert_eq!(in_memory::schemas(), None);
+    assert_eq!(
+        casper_event_standard::try_emit_or_init(Unserializable, schemas),
+        Err(Error::Serialization)
+    );
+    assert_eq!(in_memory::schemas(), None);
+}
+
+#[test]
+fn test_metadata_layout() {
+    casper_event_standard::init_with_layout(
+        "",
+        Layout::Metadata,
+        Schemas::new().with::<Transfer>(),
+    );
+    assert_eq!(in_memory::named_keys(), vec![EVENTS_DICT, EVENTS_METADATA]);
+
+    casper_event_standard::emit(mock_transfer());
+    casper_event_standard::emit_batch(vec![mock_transfer(), mock_transfer()]);
+    casper_event_standard::register_schemas(Schemas::new().with::<Mint>());
+    casper_event_standard::emit_checked(mock_mint());
+
+    assert_eq!(in_memory::schemas(), Some(schemas()));
+    assert_eq!(in_memory::emitted_events().len(), 4);
+    assert_eq!(
+        casper_event_standard::try_init(schemas()),
+        Err(Error::AlreadyInitialized)
+    );
+}
+
+#[test]
+fn test_metadata_layout_with_prefix() {
+    casper_event_standard::init_with_layout("minting", Layout::Metadata, schemas());
+    casper_event_standard::emit_or_init_to("minting", mock_mint(), || unreachable!());
+    assert_eq!(in_memory::emitted_events_with_prefix("minting").len(), 1);
+    assert_eq!(in_memory::schemas_with_prefix("minting"), Some(schemas()));
+    assert_eq!(in_memory::schemas(), None);
+}
+
+#[test]
+fn test_metadata_bytes() {
+    let metadata = EventsMetadata {
+        ces_version: CES_VERSION.to_string(),
+        events_length: 7,
+        schemas: schemas(),
+    };
+    let bytes = metadata.to_bytes().unwrap();
+    assert_eq!(bytes[0], casper_event_standard::METADATA_VERSION);
+    assert_eq!(bytes.len(), metadata.serialized_length());
+    assert_eq!(
+        EventsMetadata::from_bytes(&bytes).unwrap(),
+        (metadata, &[][..])
+    );
+
+    let mut unknown_version = bytes;
+    unknown_version[0] += 1;
+    assert!(EventsMetadata::from_bytes(&unknown_version).is_err());
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use casper_event_standard::{decode_event_to_json, value_to_json, Event, Schemas, Value};
+use casper_types::{bytesrepr::ToBytes, CLType, CLTyped, Key, U256, U512};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event)]
+struct Complex {
+    fee: Option<U512>,
+    memo: Option<String>,
+    names: BTreeMap<String, u64>,
+    owners: BTreeMap<u8, Key>,
+    status: Result<(), String>,
+    pair: (u32, [u8; 2]),
+}
+
+fn hash_key(byte: &str) -> Key {
+    Key::from_formatted_str(&format!("hash-{}", byte.repeat(64))).unwrap()
+}
+
+#[test]
+fn test_event_to_json() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let transfer = Transfer {
+        amount: U256::from(123),
+        from: hash_key("1"),
+        to: hash_key("2"),
+    };
+    let json = decode_event_to_json(&schemas, &transfer.to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        json.to_string(),
+        format!(
+            r#"{{"name":"Transfer","fields":{{"amount":"123","from":"hash-{}","to":"hash-{}"}}}}"#,
+            "1".repeat(64),
+            "2".repeat(64)
+        )
+    );
+}
+
+#[test]
+fn test_complex_event_to_json() {
+    let schemas = Schemas::new().with::<Complex>();
+    let mut names = BTreeMap::new();
+    names.insert(String::from("b"), 2);
+    names.insert(String::from("a"), 1);
+    let mut owners = BTreeMap::new();
+    owners.insert(1, hash_key("3"));
+    let event = Complex {
+        fee: Some(U512::from(10).pow(U512::from(30))),
+        memo: None,
+        names,
+        owners,
+        status: Err(String::from("failed")),
+        pair: (7, [0xab, 0x01]),
+    };
+    let json = decode_event_to_json(&schemas, &event.to_bytes().unwrap()).unwrap();
+    assert_eq!(
+        json.to_string(),
+        format!(
+            concat!(
+                r#"{{"name":"Complex","fields":{{"#,
+                r#""fee":"1000000000000000000000000000000","#,
+                r#""memo":null,"#,
+                r#""names":{{"a":1,"b":2}},"#,
+                r#""owners":[[1,"hash-{}"]],"#,
+                r#""status":{{"Err":"failed"}},"#,
+                r#""pair":[7,"ab01"]}}}}"#
+            ),
+            "3".repeat(64)
+        )
+    );
+}
+
+#[test]
+fn test_empty_map_to_json() {
+    let value = Value::Map(Vec::new());
+    assert_eq!(
+        value_to_json(&BTreeMap::<String, u8>::cl_type(), &value).to_string(),
+        "{}"
+    );
+    assert_eq!(
+        value_to_json(&BTreeMap::<u8, u8>::cl_type(), &value).to_string(),
+        "[]"
+    );
+}
+
+#[test]
+fn test_mismatched_value_to_json() {
+    let value = Value::List(Vec::new());
+    assert!(value_to_json(&CLType::Bool, &value).is_null());
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use casper_event_standard::{
+    cl_type_to_json_schema, schema_to_json_schema, schemas_to_json_schemas, Event, Schemas,
+    JSON_SCHEMA_DIALECT,
+};
+use casper_types::{CLType, CLTyped, Key, PublicKey, U256, U512};
+use serde_json::json;
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event)]
+struct Mint {
+    account: Key,
+    amount: U256,
+}
+
+#[test]
+fn test_schema_to_json_schema() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let document = schema_to_json_schema("Transfer", &schemas.0["Transfer"]);
+    let fields = &document["properties"]["fields"];
+
+    assert_eq!(document["$schema"], JSON_SCHEMA_DIALECT);
+    assert_eq!(document["title"], "Transfer");
+    assert_eq!(
+        document["properties"]["name"],
+        json!({ "const": "Transfer" })
+    );
+    assert_eq!(document["required"], json!(["name", "fields"]));
+    assert_eq!(fields["required"], json!(["amount", "from", "to"]));
+    assert_eq!(
+        fields["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["amount", "from", "to"]
+    );
+    assert_eq!(
+        fields["properties"]["amount"],
+        cl_type_to_json_schema(&CLType::U256)
+    );
+}
+
+#[test]
+fn test_schemas_to_json_schemas() {
+    let schemas = Schemas::new().with::<Transfer>().with::<Mint>();
+    let documents = schemas_to_json_schemas(&schemas);
+    let names = documents
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Mint", "Transfer"]);
+    assert_eq!(documents[0].1["title"], "Mint");
+}
+
+#[test]
+fn test_primitives_to_json_schema() {
+    assert_eq!(
+        cl_type_to_json_schema(&u8::cl_type()),
+        json!({ "type": "integer", "minimum": 0, "maximum": 255 })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&i64::cl_type())["minimum"],
+        json!(i64::MIN)
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&U512::cl_type()),
+        json!({ "type": "string", "pattern": "^(0|[1-9][0-9]*)$" })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&<[u8; 32]>::cl_type()),
+        json!({ "type": "string", "pattern": "^[0-9a-f]{64}$" })
+    );
+    assert_eq!(cl_type_to_json_schema(&Key::cl_type())["type"], "string");
+    assert_eq!(
+        cl_type_to_json_schema(&PublicKey::cl_type())["type"],
+        "string"
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&<()>::cl_type()),
+        json!({ "type": "null" })
+    );
+    assert_eq!(cl_type_to_json_schema(&CLType::Any), json!({}));
+}
+
+#[test]
+fn test_compound_types_to_json_schema() {
+    let string = json!({ "type": "string" });
+    let boolean = json!({ "type": "boolean" });
+
+    assert_eq!(
+        cl_type_to_json_schema(&Option::<String>::cl_type()),
+        json!({ "anyOf": [string, { "type": "null" }] })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&Vec::<bool>::cl_type()),
+        json!({ "type": "array", "items": boolean })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&BTreeMap::<String, bool>::cl_type()),
+        json!({ "type": "object", "additionalProperties": boolean })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&BTreeMap::<bool, String>::cl_type()),
+        json!({
+            "type": "array",
+            "items": {
+                "type": "array",
+                "prefixItems": [boolean, string],
+                "items": false,
+                "minItems": 2,
+            },
+        })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&Result::<bool, String>::cl_type()),
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": { "Ok": boolean },
+                    "required": ["Ok"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": { "Err": string },
+                    "required": ["Err"],
+                    "additionalProperties": false,
+                },
+            ],
+        })
+    );
+    assert_eq!(
+        cl_type_to_json_schema(&<(bool, String, bool)>::cl_type())["minItems"],
+        3
+    );
+}
@@ -0,0 +1,91 @@
+use casper_event_standard::{CLType2, Event, ParseTypeError, Schema, Schemas};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLType, Key, U256,
+};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Option<Key>,
+}
+
+const TYPES: [&str; 12] = [
+    "Bool",
+    "U512",
+    "PublicKey",
+    "ByteArray(32)",
+    "Option<U8>",
+    "List<String>",
+    "Map<String, List<Option<U256>>>",
+    "Result<Unit, String>",
+    "Tuple1<URef>",
+    "Tuple2<I32, I64>",
+    "Tuple3<Key, Map<U8, Bool>, ByteArray(0)>",
+    "Any",
+];
+
+#[test]
+fn test_cl_type_round_trip() {
+    for notation in TYPES {
+        let ty: CLType2 = notation.parse().unwrap();
+        let bytes = ty.to_bytes().unwrap();
+        let (decoded, rest) = CLType2::from_bytes(&bytes).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(decoded.to_string(), notation);
+    }
+}
+
+#[test]
+fn test_cl_type_parse() {
+    let ty: CLType2 = " Map< String ,List<Option<U256>> > ".parse().unwrap();
+    assert_eq!(
+        ty.downcast(),
+        CLType::Map {
+            key: Box::new(CLType::String),
+            value: Box::new(CLType::List(Box::new(CLType::Option(Box::new(
+                CLType::U256
+            ))))),
+        }
+    );
+}
+
+#[test]
+fn test_cl_type_parse_errors() {
+    let parse = |s: &str| s.parse::<CLType2>().unwrap_err();
+    assert_eq!(parse("U1024"), ParseTypeError::UnknownType("U1024".into()));
+    assert_eq!(parse(""), ParseTypeError::Expected("a type"));
+    assert_eq!(parse("Option<U8"), ParseTypeError::Expected(">"));
+    assert_eq!(parse("Result<U8>"), ParseTypeError::Expected(","));
+    assert_eq!(
+        parse("ByteArray(x)"),
+        ParseTypeError::Expected("a byte array length")
+    );
+    assert_eq!(parse("U8 U8"), ParseTypeError::TrailingInput("U8".into()));
+}
+
+#[test]
+fn test_schema_round_trip() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let schema = &schemas.0["Transfer"];
+    let notation = "{amount: U256, from: Key, to: Option<Key>}";
+
+    assert_eq!(schema.to_string(), notation);
+    assert_eq!(&notation.parse::<Schema>().unwrap(), schema);
+    assert_eq!("{ }".parse::<Schema>().unwrap(), Schema::new());
+    assert_eq!(Schema::new().to_string(), "{}");
+}
+
+#[test]
+fn test_schema_parse_errors() {
+    let parse = |s: &str| s.parse::<Schema>().unwrap_err();
+    assert_eq!(parse("amount: U256"), ParseTypeError::Expected("{"));
+    assert_eq!(parse("{: U256}"), ParseTypeError::Expected("a field name"));
+    assert_eq!(parse("{amount U256}"), ParseTypeError::Expected(":"));
+    assert_eq!(parse("{amount: U256"), ParseTypeError::Expected(","));
+    assert_eq!(
+        parse("{amount: U256,}"),
+        ParseTypeError::Expected("a field name")
+    );
+}
@@ -0,0 +1,224 @@
+use casper_event_standard::{CLType2, Compatibility, Error, Event, FieldChange, Schema, Schemas};
+use casper_types::{bytesrepr::ToBytes, CLTyped, Key, U256};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event)]
+struct Mint {
+    account: Key,
+    amount: U256,
+}
+
+#[derive(Event)]
+#[event(name = "Mint")]
+struct OtherMint {
+    amount: U256,
+}
+
+fn mock_transfer() -> Transfer {
+    Transfer {
+        amount: U256::from(123),
+        from: Key::from_formatted_str(
+            "hash-1111111111111111111111111111111111111111111111111111111111111111",
+        )
+        .unwrap(),
+        to: Key::from_formatted_str(
+            "hash-2222222222222222222222222222222222222222222222222222222222222222",
+        )
+        .unwrap(),
+    }
+}
+
+#[test]
+fn test_validate_registered_event() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(schemas.validate(&bytes), Ok(()));
+}
+
+#[test]
+fn test_validate_unknown_event() {
+    let schemas = Schemas::new().with::<Mint>();
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(schemas.validate(&bytes), Err(Error::UnknownEvent));
+
+    let bytes = "Transfer".to_bytes().unwrap();
+    assert_eq!(schemas.validate(&bytes), Err(Error::UnknownEvent));
+}
+
+#[test]
+fn test_validate_mismatched_event() {
+    let mut schema = Schema::new();
+    schema.with_elem("amount", U256::cl_type());
+    schema.with_elem("from", Key::cl_type());
+    schema.with_elem("to", String::cl_type());
+    let mut schemas = Schemas::new();
+    schemas.0.insert(String::from("Transfer"), schema);
+
+    let bytes = mock_transfer().to_bytes().unwrap();
+    assert_eq!(schemas.validate(&bytes), Err(Error::InvalidEvent));
+}
+
+#[test]
+fn test_validate_trailing_bytes() {
+    let schemas = Schemas::new().with::<Transfer>();
+    let mut bytes = mock_transfer().to_bytes().unwrap();
+    bytes.push(0);
+    assert_eq!(schemas.validate(&bytes), Err(Error::InvalidEvent));
+    assert_eq!(schemas.validate(&[]), Err(Error::InvalidEvent));
+}
+
+#[test]
+fn test_merge() {
+    let mut schemas = Schemas::new().with::<Transfer>();
+    let result = schemas.merge(Schemas::new().with::<Transfer>().with::<Mint>());
+    assert_eq!(result, Ok(()));
+    assert_eq!(schemas, Schemas::new().with::<Transfer>().with::<Mint>());
+}
+
+#[test]
+fn test_merge_incompatible() {
+    let mut schema = Schema::new();
+    schema.with_elem("amount", U256::cl_type());
+    let mut incompatible = Schemas::new().with::<Mint>();
+    incompatible.0.insert(String::from("Transfer"), schema);
+
+    let mut schemas = Schemas::new().with::<Transfer>();
+    let result = schemas.merge(incompatible);
+    assert_eq!(result, Err(Error::IncompatibleSchema));
+    assert_eq!(schemas, Schemas::new().with::<Transfer>());
+}
+
+fn schemas_with(name: &str, elems: &[(&str, casper_types::CLType)]) -> Schemas {
+    let mut schema = Schema::new();
+    for (elem_name, ty) in elems {
+        schema.with_elem(elem_name, ty.clone());
+    }
+    let mut schemas = Schemas::new();
+    schemas.0.insert(String::from(name), schema);
+    schemas
+}
+
+#[test]
+fn test_diff_identical() {
+    let schemas = Schemas::new().with::<Transfer>().with::<Mint>();
+    let diff = schemas.diff(&schemas.clone());
+    assert!(diff.is_empty());
+    assert_eq!(diff.compatibility(), Compatibility::Identical);
+}
+
+#[test]
+fn test_diff_added_and_removed_events() {
+    let old = Schemas::new().with::<Transfer>();
+    let new = Schemas::new().with::<Transfer>().with::<Mint>();
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.added, vec![String::from("Mint")]);
+    assert!(diff.removed.is_empty());
+    assert_eq!(old.compatibility(&new), Compatibility::Compatible);
+
+    let diff = new.diff(&old);
+    assert_eq!(diff.removed, vec![String::from("Mint")]);
+    assert!(diff.added.is_empty());
+    assert_eq!(new.compatibility(&old), Compatibility::Incompatible);
+}
+
+#[test]
+fn test_diff_field_changes() {
+    let old = schemas_with(
+        "Transfer",
+        &[
+            ("amount", U256::cl_type()),
+            ("from", Key::cl_type()),
+            ("to", Key::cl_type()),
+            ("memo", String::cl_type()),
+        ],
+    );
+    let new = schemas_with(
+        "Transfer",
+        &[
+            ("fee", U256::cl_type()),
+            ("from", Key::cl_type()),
+            ("amount", u64::cl_type()),
+            ("to", Key::cl_type()),
+        ],
+    );
+
+    let diff = old.diff(&new);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        diff.changed.get("Transfer").unwrap(),
+        &vec![
+            FieldChange::Removed {
+                name: String::from("memo"),
+                ty: CLType2(String::cl_type()),
+            },
+            FieldChange::Added {
+                name: String::from("fee"),
+                ty: CLType2(U256::cl_type()),
+            },
+            FieldChange::Reordered {
+                name: String::from("amount"),
+                from: 0,
+                to: 2,
+            },
+            FieldChange::TypeChanged {
+                name: String::from("amount"),
+                from: CLType2(U256::cl_type()),
+                to: CLType2(u64::cl_type()),
+            },
+        ]
+    );
+    assert_eq!(diff.compatibility(), Compatibility::Incompatible);
+}
+
+#[test]
+fn test_diff_single_field_move() {
+    let old = schemas_with(
+        "Transfer",
+        &[
+            ("a", U256::cl_type()),
+            ("b", Key::cl_type()),
+            ("c", String::cl_type()),
+        ],
+    );
+    let new = schemas_with(
+        "Transfer",
+        &[
+            ("b", Key::cl_type()),
+            ("c", String::cl_type()),
+            ("a", U256::cl_type()),
+        ],
+    );
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changed.get("Transfer").unwrap(),
+        &vec![FieldChange::Reordered {
+            name: String::from("a"),
+            from: 0,
+            to: 2,
+        }]
+    );
+}
+
+#[test]
+fn test_add_duplicate_event() {
+    let mut schemas = Schemas::new().with::<Mint>();
+    assert_eq!(schemas.try_add::<OtherMint>(), Err(Error::DuplicateEvent));
+    assert_eq!(schemas.try_add::<Mint>(), Err(Error::DuplicateEvent));
+    assert_eq!(schemas, Schemas::new().with::<Mint>());
+    assert_eq!(schemas.try_add::<Transfer>(), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "Event is already registered.")]
+fn test_with_duplicate_event_panics() {
+    let _ = Schemas::new().with::<Mint>().with::<OtherMint>();
+}
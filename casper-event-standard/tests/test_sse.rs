@@ -0,0 +1,160 @@
+use casper_event_standard::{
+    decode_event, events_from_message, events_from_sse, DecodedEvent, Event, Schemas, SseError,
+    SseEvent,
+};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, Key, URef, U256};
+use serde_json::{json, Value as JsonValue};
+
+const DEPLOY_PROCESSED: &str = include_str!("fixtures/deploy_processed.json");
+const TRANSACTION_PROCESSED: &str = include_str!("fixtures/transaction_processed.json");
+const EVENTS_SSE: &str = include_str!("fixtures/events.sse");
+
+const EVENTS_DICT: &str =
+    "uref-7c57cd4f2e1c9e4b65b4d07ad3c1dc69e5a81437f8e9a4bc49d63f5087e0a1c2-007";
+const BALANCES_DICT: &str =
+    "uref-e1bb0f1c0b2bd9c0d4e6a9f52a6f4c3371b0c6a8e9a5dd2c3a6b43d4b1f8a907-007";
+
+#[derive(Event)]
+struct Mint {
+    recipient: Key,
+    amount: U256,
+}
+
+#[derive(Event)]
+struct Burn {
+    owner: Key,
+    amount: U256,
+}
+
+fn schemas() -> Schemas {
+    Schemas::new().with::<Mint>().with::<Burn>()
+}
+
+fn events_dict() -> URef {
+    URef::from_formatted_str(EVENTS_DICT).unwrap()
+}
+
+fn account(byte: u8) -> Key {
+    Key::Account(AccountHash::new([byte; 32]))
+}
+
+fn decoded<T: ToBytes>(event: T) -> DecodedEvent {
+    decode_event(&schemas(), &event.to_bytes().unwrap()).unwrap()
+}
+
+fn sse_event<T: ToBytes>(hash: u8, index: u32, event: T) -> SseEvent {
+    SseEvent {
+        hash: format!("{:02x}", hash).repeat(32),
+        index,
+        event: decoded(event),
+    }
+}
+
+fn deploy_events() -> Vec<SseEvent> {
+    vec![
+        sse_event(
+            0xa1,
+            0,
+            Mint {
+                recipient: account(0x22),
+                amount: U256::from(100),
+            },
+        ),
+        sse_event(
+            0xa1,
+            1,
+            Burn {
+                owner: account(0x22),
+                amount: U256::from(40),
+            },
+        ),
+    ]
+}
+
+fn transaction_events() -> Vec<SseEvent> {
+    vec![sse_event(
+        0xb2,
+        2,
+        Mint {
+            recipient: account(0x33),
+            amount: U256::from(5),
+        },
+    )]
+}
+
+#[test]
+fn test_deploy_processed() {
+    let events = events_from_sse(DEPLOY_PROCESSED, events_dict(), &schemas()).unwrap();
+    assert_eq!(events, deploy_events());
+}
+
+#[test]
+fn test_transaction_processed() {
+    let events = events_from_sse(TRANSACTION_PROCESSED, events_dict(), &schemas()).unwrap();
+    assert_eq!(events, transaction_events());
+}
+
+#[test]
+fn test_raw_event_stream() {
+    // Failed executions and the other messages are skipped.
+    let events = events_from_sse(EVENTS_SSE, events_dict(), &schemas()).unwrap();
+    assert_eq!(events, [deploy_events(), transaction_events()].concat());
+}
+
+#[test]
+fn test_json_array_and_single_message() {
+    let input = format!("[{}, {}]", TRANSACTION_PROCESSED, DEPLOY_PROCESSED);
+    let events = events_from_sse(&input, events_dict(), &schemas()).unwrap();
+    assert_eq!(events, [transaction_events(), deploy_events()].concat());
+
+    let message = serde_json::from_str(DEPLOY_PROCESSED).unwrap();
+    let events = events_from_message(&message, events_dict(), &schemas());
+    assert_eq!(events, deploy_events());
+}
+
+#[test]
+fn test_other_dictionary() {
+    // The balances dictionary uses account hashes as the item keys.
+    let balances = URef::from_formatted_str(BALANCES_DICT).unwrap();
+    let events = events_from_sse(EVENTS_SSE, balances, &schemas()).unwrap();
+    assert!(events.is_empty());
+
+    let other = URef::from_formatted_str(&EVENTS_DICT.replace("7c57", "0000")).unwrap();
+    let events = events_from_sse(EVENTS_SSE, other, &schemas()).unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_unknown_event() {
+    // Events that can't be decoded are skipped.
+    let schemas = Schemas::new().with::<Mint>();
+    let events = events_from_sse(DEPLOY_PROCESSED, events_dict(), &schemas).unwrap();
+    assert_eq!(events, deploy_events()[..1]);
+}
+
+#[test]
+fn test_malformed_writes() {
+    // Malformed writes of other contracts don't hide the events.
+    let mut message: JsonValue = serde_json::from_str(DEPLOY_PROCESSED).unwrap();
+    let transforms = message["DeployProcessed"]["execution_result"]["Success"]["effect"]
+        ["transforms"]
+        .as_array_mut()
+        .unwrap();
+    for bytes in ["zz", "0102", ""] {
+        transforms.insert(
+            0,
+            json!({
+                "key": format!("dictionary-{}", "ab".repeat(32)),
+                "transform": {"WriteCLValue": {"cl_type": "Any", "bytes": bytes, "parsed": null}}
+            }),
+        );
+    }
+    let events = events_from_message(&message, events_dict(), &schemas());
+    assert_eq!(events, deploy_events());
+}
+
+#[test]
+fn test_invalid_json() {
+    let result = events_from_sse("data:{\"DeployProcessed\":", events_dict(), &schemas());
+    assert!(matches!(result, Err(SseError::Json(_))));
+}
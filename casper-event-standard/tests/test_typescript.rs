@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use casper_event_standard::{schemas_to_typescript, Event, Schema, Schemas};
+use casper_types::{CLType, Key, PublicKey, U256, U512};
+
+#[derive(Event)]
+struct Transfer {
+    amount: U256,
+    from: Key,
+    to: Key,
+}
+
+#[derive(Event)]
+struct Complex {
+    fee: Option<U512>,
+    names: BTreeMap<String, u64>,
+    status: Result<(), String>,
+    pair: (u32, [u8; 2]),
+    owners: Vec<Option<PublicKey>>,
+    #[event(rename = "block-height")]
+    height: i64,
+}
+
+fn lines(code: &str, start: &str) -> Vec<String> {
+    code.lines()
+        .skip_while(|line| *line != start)
+        .take_while(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn test_transfer_to_typescript() {
+    let code = schemas_to_typescript(&Schemas::new().with::<Transfer>());
+
+    assert!(code.starts_with("// Generated by casper-event-standard."));
+    assert_eq!(
+        lines(&code, "export interface Transfer {"),
+        vec![
+            "export interface Transfer {",
+            "  amount: bigint;",
+            "  from: string;",
+            "  to: string;",
+            "}",
+        ]
+    );
+    assert_eq!(
+        lines(
+            &code,
+            "export function decodeTransfer(bytes: Uint8Array): Transfer {"
+        ),
+        vec![
+            "export function decodeTransfer(bytes: Uint8Array): Transfer {",
+            "  const r = new Reader(bytes);",
+            "  r.expectName(\"event_Transfer\");",
+            "  const event: Transfer = {",
+            "    amount: r.bigUint(),",
+            "    from: r.key(),",
+            "    to: r.key(),",
+            "  };",
+            "  r.expectEnd();",
+            "  return event;",
+            "}",
+        ]
+    );
+    assert_eq!(
+        lines(&code, "export type Event ="),
+        vec![
+            "export type Event =",
+            "  | { name: \"Transfer\"; data: Transfer };",
+        ]
+    );
+}
+
+#[test]
+fn test_complex_to_typescript() {
+    let code = schemas_to_typescript(&Schemas::new().with::<Complex>());
+
+    assert_eq!(
+        lines(&code, "export interface Complex {"),
+        vec![
+            "export interface Complex {",
+            "  fee: bigint | null;",
+            "  names: Array<[string, bigint]>;",
+            "  status: Result<null, string>;",
+            "  pair: [number, Uint8Array];",
+            "  owners: Array<string | null>;",
+            "  \"block-height\": bigint;",
+            "}",
+        ]
+    );
+    assert_eq!(
+        lines(&code, "  const event: Complex = {"),
+        vec![
+            "  const event: Complex = {",
+            "    fee: r.option(() => r.bigUint()),",
+            "    names: r.map(() => r.string(), () => r.u64()),",
+            "    status: r.result(() => r.unit(), () => r.string()),",
+            "    pair: [r.u32(), r.bytes(2)],",
+            "    owners: r.list(() => r.option(() => r.publicKey())),",
+            "    \"block-height\": r.i64(),",
+            "  };",
+            "  r.expectEnd();",
+            "  return event;",
+            "}",
+        ]
+    );
+}
+
+#[test]
+fn test_decode_event_to_typescript() {
+    let schemas = Schemas::new().with::<Transfer>().with::<Complex>();
+    let code = schemas_to_typescript(&schemas);
+
+    assert_eq!(
+        lines(
+            &code,
+            "export function decodeEvent(bytes: Uint8Array): Event {"
+        ),
+        vec![
+            "export function decodeEvent(bytes: Uint8Array): Event {",
+            "  const name = new Reader(bytes).string();",
+            "  switch (name) {",
+            "    case \"event_Complex\":",
+            "      return { name: \"Complex\", data: decodeComplex(bytes) };",
+            "    case \"event_Transfer\":",
+            "      return { name: \"Transfer\", data: decodeTransfer(bytes) };",
+            "    default:",
+            "      throw new Error(`Unknown event: ${name}`);",
+            "  }",
+            "}",
+        ]
+    );
+}
+
+#[test]
+fn test_names_to_typescript() {
+    let mut schema = Schema::new();
+    schema.with_elem("value", CLType::Any);
+    let mut schemas = Schemas::new();
+    schemas.0.insert(String::from("2nd \"mint\""), schema);
+    schemas.0.insert(String::from("Empty"), Schema::new());
+    let code = schemas_to_typescript(&schemas);
+
+    assert!(code.contains("export interface _2nd__mint_ {\n  value: unknown;\n}"));
+    assert!(code.contains("  r.expectName(\"event_2nd \\\"mint\\\"\");"));
+    assert!(code.contains("    value: r.any(),"));
+    assert!(code.contains("export interface Empty {\n}"));
+    assert!(schemas_to_typescript(&Schemas::new()).contains("export type Event = never;"));
+}
+
+#[test]
+fn test_colliding_names() {
+    let mut schemas = Schemas::new();
+    for name in ["a-b", "a_b", "Event", "Reader", "string"] {
+        schemas.0.insert(String::from(name), Schema::new());
+    }
+    let code = schemas_to_typescript(&schemas);
+
+    // `a-b` comes first in the `Schemas` order and takes `a_b`.
+    assert!(code.contains("export interface a_b {"));
+    assert!(code.contains("export interface a_b_2 {"));
+    assert!(code.contains("  r.expectName(\"event_a-b\");\n  const event: a_b = {"));
+    assert!(code.contains("  r.expectName(\"event_a_b\");\n  const event: a_b_2 = {"));
+    assert!(code.contains("export interface Event_2 {"));
+    assert!(code.contains("export function decodeEvent_2(bytes: Uint8Array): Event_2 {"));
+    assert!(code.contains("export interface Reader_2 {"));
+    assert!(code.contains("export interface string_2 {"));
+    assert!(code.contains("  | { name: \"a_b\"; data: a_b_2 }"));
+    assert!(code.contains("      return { name: \"a-b\", data: decodea_b(bytes) };"));
+    assert!(!code.contains("export interface Event {"));
+    assert_eq!(code.matches("export function decodeEvent(").count(), 1);
+}
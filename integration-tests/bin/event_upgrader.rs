@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use casper_event_standard::Schemas;
+use integration_tests::Burn;
+
+#[no_mangle]
+fn call() {
+    let mut schemas = Schemas::new();
+    schemas.add::<Burn>();
+    casper_event_standard::register_schemas(schemas);
+    casper_event_standard::emit_checked(integration_tests::mock_burn_1());
+}
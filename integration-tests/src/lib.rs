@@ -18,6 +18,12 @@ pub struct Mint {
     amount: U256,
 }
 
+#[derive(Event, Debug, PartialEq)]
+pub struct Burn {
+    account: Key,
+    amount: U256,
+}
+
 pub fn mock_transfer_1() -> Transfer {
     Transfer {
         amount: U256::from(123),
@@ -65,3 +71,13 @@ pub fn mock_mint_2() -> Mint {
         amount: U256::from(456),
     }
 }
+
+pub fn mock_burn_1() -> Burn {
+    Burn {
+        account: Key::from_formatted_str(
+            "hash-7777777777777777777777777777777777777777777777777777777777777777",
+        )
+        .unwrap(),
+        amount: U256::from(567),
+    }
+}
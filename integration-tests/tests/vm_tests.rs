@@ -3,7 +3,8 @@ use casper_engine_test_support::{
     DEFAULT_CHAINSPEC_REGISTRY, DEFAULT_GENESIS_CONFIG, DEFAULT_GENESIS_CONFIG_HASH,
 };
 use casper_event_standard::{
-    Schemas, CES_VERSION, CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH, EVENTS_SCHEMA,
+    EventKeys, EventsMetadata, Schemas, CES_VERSION, CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH,
+    EVENTS_METADATA, EVENTS_SCHEMA,
 };
 use casper_execution_engine::core::engine_state::{
     run_genesis_request::RunGenesisRequest, GenesisAccount,
@@ -14,7 +15,7 @@ use casper_types::{
     contracts::NamedKeys,
     Key, Motes, PublicKey, RuntimeArgs, SecretKey, StoredValue, URef, U512,
 };
-use integration_tests::{Mint, Transfer};
+use integration_tests::{Burn, Mint, Transfer};
 
 struct TestEnv {
     context: InMemoryWasmTestBuilder,
@@ -67,6 +68,14 @@ impl TestEnv {
         self.deploy_wasm("event_initializer.wasm")
     }
 
+    pub fn deploy_event_upgrader_wasm(&mut self) {
+        self.deploy_wasm("event_upgrader.wasm")
+    }
+
+    pub fn deploy_event_metadata_initializer_wasm(&mut self) {
+        self.deploy_wasm("event_metadata_initializer.wasm")
+    }
+
     pub fn named_keys(&self) -> NamedKeys {
         self.context
             .get_expected_account(self.default_account())
@@ -110,6 +119,15 @@ impl TestEnv {
             .unwrap()
     }
 
+    pub fn metadata(&self) -> EventsMetadata {
+        let key = Key::from(self.default_account());
+        EventsMetadata::read(&EventKeys::default(), |name| {
+            let value = self.context.query(None, key, &[String::from(name)]).ok()?;
+            value.as_cl_value().cloned()
+        })
+        .unwrap()
+    }
+
     pub fn event_at<T: FromBytes>(&self, index: u32) -> Option<T> {
         let dictionary_seed_uref: URef = *self
             .named_keys()
@@ -153,6 +171,7 @@ fn test_events_initalization() {
     assert!(named_keys.contains_key(CES_VERSION_KEY));
     assert_eq!(test_env.events_length(), 0);
     assert_eq!(test_env.ces_version(), CES_VERSION);
+    assert_eq!(test_env.metadata().events_length, 0);
 
     let schemas = test_env.schemas();
     let mut expected_schemas = Schemas::new();
@@ -181,3 +200,51 @@ fn test_events_emission() {
     let mint_2: Mint = test_env.event_at(3).unwrap();
     assert_eq!(mint_2, integration_tests::mock_mint_2());
 }
+
+#[test]
+fn test_events_upgrade() {
+    let mut test_env = TestEnv::new();
+    test_env.deploy_event_initializer_wasm();
+    test_env.deploy_event_producer_wasm();
+    test_env.deploy_event_upgrader_wasm();
+
+    assert_eq!(test_env.events_length(), 5);
+
+    let mint_2: Mint = test_env.event_at(3).unwrap();
+    assert_eq!(mint_2, integration_tests::mock_mint_2());
+
+    let burn_1: Burn = test_env.event_at(4).unwrap();
+    assert_eq!(burn_1, integration_tests::mock_burn_1());
+
+    let schemas = test_env.schemas();
+    let mut expected_schemas = Schemas::new();
+    expected_schemas.add::<Transfer>();
+    expected_schemas.add::<Mint>();
+    expected_schemas.add::<Burn>();
+    assert_eq!(schemas, expected_schemas);
+}
+
+#[test]
+fn test_events_metadata_layout() {
+    let mut test_env = TestEnv::new();
+    test_env.deploy_event_metadata_initializer_wasm();
+    test_env.deploy_event_producer_wasm();
+
+    let named_keys = test_env.named_keys();
+    assert!(named_keys.contains_key(EVENTS_DICT));
+    assert!(named_keys.contains_key(EVENTS_METADATA));
+    assert!(!named_keys.contains_key(EVENTS_LENGTH));
+    assert!(!named_keys.contains_key(EVENTS_SCHEMA));
+    assert!(!named_keys.contains_key(CES_VERSION_KEY));
+
+    let metadata = test_env.metadata();
+    assert_eq!(metadata.events_length, 4);
+    assert_eq!(metadata.ces_version, CES_VERSION);
+    let mut expected_schemas = Schemas::new();
+    expected_schemas.add::<Transfer>();
+    expected_schemas.add::<Mint>();
+    assert_eq!(metadata.schemas, expected_schemas);
+
+    let mint_2: Mint = test_env.event_at(3).unwrap();
+    assert_eq!(mint_2, integration_tests::mock_mint_2());
+}